@@ -0,0 +1,145 @@
+use super::Complex;
+
+use num_traits::{Float, FloatConst};
+
+
+/// Follows a path of points around the origin and keeps track of the sheet of the
+/// logarithm it is on, so that `ln` and `powf` stay continuous where the principal
+/// branch would jump across the negative real axis.
+///
+/// Consecutive points are assumed to be close enough that the true change in argument
+/// between them is less than pi.
+#[derive(Clone, Copy, Debug)]
+pub struct BranchTracker<N: Float> {
+    branch: isize,
+    argument: Option<N>
+}
+
+impl<N: Float+FloatConst> BranchTracker<N> {
+    pub fn new(branch: isize) -> Self {
+        return Self {
+            branch: branch,
+            argument: None
+        };
+    }
+
+    pub fn branch(&self) -> isize {
+        return self.branch;
+    }
+
+    /// Moves the path to `point` and returns the sheet it now lies on.
+    pub fn advance(&mut self, point: &Complex<N>) -> isize {
+        let argument = point.argument();
+
+        if let Some(previous) = self.argument {
+            let delta = argument - previous;
+
+            if delta > N::PI() {
+                self.branch -= 1;
+            } else if delta < -N::PI() {
+                self.branch += 1;
+            }
+        }
+
+        self.argument = Some(argument);
+
+        return self.branch;
+    }
+
+    /// Continuous argument of `point` along the path.
+    pub fn argument(&mut self, point: &Complex<N>) -> N {
+        let branch = self.advance(point);
+
+        return point.argument() + N::TAU() * N::from(branch).unwrap();
+    }
+
+    pub fn ln(&mut self, point: &Complex<N>) -> Complex<N> {
+        let branch = self.advance(point);
+
+        return point.ln_branch(branch);
+    }
+
+    pub fn powf(&mut self, point: &Complex<N>, exponent: N) -> Complex<N> {
+        let branch = self.advance(point);
+
+        return point.powf_branch(exponent, branch);
+    }
+}
+
+impl<N: Float+FloatConst> Default for BranchTracker<N> {
+    fn default() -> Self {
+        return Self::new(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f64::consts::{PI, TAU};
+
+    // `turns` full turns around the origin at radius 2, starting at angle 1, in `steps`
+    // steps per turn; negative turns go clockwise.
+    fn circle(turns: isize, steps: usize) -> impl Iterator<Item = (f64, Complex<f64>)> {
+        let count = turns.unsigned_abs() * steps;
+        let step = TAU / steps as f64 * (turns.signum() as f64);
+
+        return (0..=count).map(move |i| {
+            let angle = 1.0 + step * i as f64;
+
+            return (angle, Complex::from_argument_amplitude(angle, 2.0));
+        });
+    }
+
+    #[test]
+    fn continuous_around_origin() {
+        for turns in [2, -2] {
+            let mut tracker = BranchTracker::default();
+            let mut previous: Option<f64> = None;
+
+            for (angle, point) in circle(turns, 64) {
+                let ln = tracker.ln(&point);
+
+                assert!((ln.imaginary - angle).abs() < 1e-12, "{ln:?} at angle {angle}");
+                assert!((ln.real - 2f64.ln()).abs() < 1e-15);
+
+                if let Some(previous) = previous {
+                    assert!((ln.imaginary - previous).abs() < TAU / 64.0 + 1e-12);
+                }
+                previous = Some(ln.imaginary);
+            }
+
+            assert_eq!(tracker.branch(), turns);
+        }
+    }
+
+    #[test]
+    fn square_root_changes_sign_after_one_turn() {
+        let start = Complex::from_argument_amplitude(1.0, 2.0);
+        let root = start.powf(0.5);
+        let mut tracker = BranchTracker::new(0);
+        let mut last = root;
+
+        for (_, point) in circle(1, 32) {
+            last = tracker.powf(&point, 0.5);
+        }
+
+        assert!(Complex::add(&last, &root).amplitude() < 1e-14);
+
+        for (_, point) in circle(1, 32) {
+            last = tracker.powf(&point, 0.5);
+        }
+
+        assert!(Complex::sub(&last, &root).amplitude() < 1e-14);
+    }
+
+    #[test]
+    fn argument() {
+        let mut tracker = BranchTracker::new(1);
+
+        assert_eq!(tracker.argument(&Complex::new(1.0, 0.0)), TAU);
+        assert!((tracker.argument(&Complex::new(-1.0, 1e-9)) - 3.0 * PI).abs() < 1e-8);
+        assert!((tracker.argument(&Complex::new(-1.0, -1e-9)) - 3.0 * PI).abs() < 1e-8);
+        assert_eq!(tracker.branch(), 2);
+    }
+}
@@ -0,0 +1,209 @@
+use super::Complex;
+
+use core::fmt::{self, Display};
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteLengthError {
+    /// A single value needs exactly `expected` bytes.
+    Exact { expected: usize, found: usize },
+    /// A slice of values needs a multiple of `size` bytes.
+    Multiple { size: usize, found: usize }
+}
+
+impl Display for ByteLengthError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Self::Exact { expected, found } => write!(formatter, "expected {expected} bytes, found {found}"),
+            Self::Multiple { size, found } => write!(formatter, "byte length {found} is not a multiple of {size}")
+        };
+    }
+}
+
+impl std::error::Error for ByteLengthError {}
+
+// The real part is stored first, then the imaginary part, each in the requested byte order.
+macro_rules! impl_bytes {
+    ($float:ty, $size:literal) => {
+        impl Complex<$float> {
+            pub const BYTES: usize = 2 * $size;
+
+            fn encode_with(&self, convert: fn($float) -> [u8; $size]) -> [u8; 2 * $size] {
+                let mut bytes = [0; 2 * $size];
+
+                bytes[..$size].copy_from_slice(&convert(self.real));
+                bytes[$size..].copy_from_slice(&convert(self.imaginary));
+
+                return bytes;
+            }
+
+            fn decode_with(bytes: &[u8], convert: fn([u8; $size]) -> $float) -> Self {
+                let (real, imaginary) = bytes.split_at($size);
+
+                return Self::new(convert(real.try_into().unwrap()), convert(imaginary.try_into().unwrap()));
+            }
+
+            fn try_decode_with(bytes: &[u8], convert: fn([u8; $size]) -> $float) -> Result<Self, ByteLengthError> {
+                if bytes.len() != Self::BYTES {
+                    return Err(ByteLengthError::Exact { expected: Self::BYTES, found: bytes.len() });
+                }
+
+                return Ok(Self::decode_with(bytes, convert));
+            }
+
+            fn slice_encode_with(values: &[Self], convert: fn($float) -> [u8; $size]) -> Vec<u8> {
+                let mut bytes = Vec::with_capacity(values.len() * Self::BYTES);
+
+                for value in values {
+                    bytes.extend_from_slice(&value.encode_with(convert));
+                }
+
+                return bytes;
+            }
+
+            fn slice_decode_with(bytes: &[u8], convert: fn([u8; $size]) -> $float) -> Result<Vec<Self>, ByteLengthError> {
+                if bytes.len() % Self::BYTES != 0 {
+                    return Err(ByteLengthError::Multiple { size: Self::BYTES, found: bytes.len() });
+                }
+
+                return Ok(bytes.chunks_exact(Self::BYTES).map(|chunk| Self::decode_with(chunk, convert)).collect());
+            }
+
+            pub fn to_le_bytes(&self) -> [u8; 2 * $size] {
+                return self.encode_with(<$float>::to_le_bytes);
+            }
+
+            pub fn to_be_bytes(&self) -> [u8; 2 * $size] {
+                return self.encode_with(<$float>::to_be_bytes);
+            }
+
+            pub fn to_ne_bytes(&self) -> [u8; 2 * $size] {
+                return self.encode_with(<$float>::to_ne_bytes);
+            }
+
+            pub fn from_le_bytes(bytes: [u8; 2 * $size]) -> Self {
+                return Self::decode_with(&bytes, <$float>::from_le_bytes);
+            }
+
+            pub fn from_be_bytes(bytes: [u8; 2 * $size]) -> Self {
+                return Self::decode_with(&bytes, <$float>::from_be_bytes);
+            }
+
+            pub fn from_ne_bytes(bytes: [u8; 2 * $size]) -> Self {
+                return Self::decode_with(&bytes, <$float>::from_ne_bytes);
+            }
+
+            pub fn try_from_le_bytes(bytes: &[u8]) -> Result<Self, ByteLengthError> {
+                return Self::try_decode_with(bytes, <$float>::from_le_bytes);
+            }
+
+            pub fn try_from_be_bytes(bytes: &[u8]) -> Result<Self, ByteLengthError> {
+                return Self::try_decode_with(bytes, <$float>::from_be_bytes);
+            }
+
+            pub fn try_from_ne_bytes(bytes: &[u8]) -> Result<Self, ByteLengthError> {
+                return Self::try_decode_with(bytes, <$float>::from_ne_bytes);
+            }
+
+            pub fn slice_to_le_bytes(values: &[Self]) -> Vec<u8> {
+                return Self::slice_encode_with(values, <$float>::to_le_bytes);
+            }
+
+            pub fn slice_to_be_bytes(values: &[Self]) -> Vec<u8> {
+                return Self::slice_encode_with(values, <$float>::to_be_bytes);
+            }
+
+            pub fn slice_to_ne_bytes(values: &[Self]) -> Vec<u8> {
+                return Self::slice_encode_with(values, <$float>::to_ne_bytes);
+            }
+
+            pub fn slice_from_le_bytes(bytes: &[u8]) -> Result<Vec<Self>, ByteLengthError> {
+                return Self::slice_decode_with(bytes, <$float>::from_le_bytes);
+            }
+
+            pub fn slice_from_be_bytes(bytes: &[u8]) -> Result<Vec<Self>, ByteLengthError> {
+                return Self::slice_decode_with(bytes, <$float>::from_be_bytes);
+            }
+
+            pub fn slice_from_ne_bytes(bytes: &[u8]) -> Result<Vec<Self>, ByteLengthError> {
+                return Self::slice_decode_with(bytes, <$float>::from_ne_bytes);
+            }
+        }
+    };
+}
+
+impl_bytes!(f32, 4);
+impl_bytes!(f64, 8);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_bytes() {
+        let z = Complex::new(1.0f32, -2.0);
+
+        assert_eq!(z.to_le_bytes(), [0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0xc0]);
+        assert_eq!(z.to_be_bytes(), [0x3f, 0x80, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00]);
+        assert_eq!(Complex::<f32>::from_le_bytes([0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0xc0]), z);
+        assert_eq!(Complex::<f32>::from_be_bytes([0x3f, 0x80, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00]), z);
+
+        let z = Complex::new(1.0f64, -2.0);
+        let le = [0, 0, 0, 0, 0, 0, 0xf0, 0x3f, 0, 0, 0, 0, 0, 0, 0, 0xc0];
+        let be = [0x3f, 0xf0, 0, 0, 0, 0, 0, 0, 0xc0, 0, 0, 0, 0, 0, 0, 0];
+
+        assert_eq!(z.to_le_bytes(), le);
+        assert_eq!(z.to_be_bytes(), be);
+        assert_eq!(Complex::<f64>::from_le_bytes(le), z);
+        assert_eq!(Complex::<f64>::from_be_bytes(be), z);
+        assert_eq!(Complex::<f64>::try_from_be_bytes(&be), Ok(z));
+    }
+
+    #[test]
+    fn round_trip_f32() {
+        let values = [Complex::new(1.5f32, -0.0), Complex::new(f32::MIN_POSITIVE, f32::MAX), Complex::new(f32::INFINITY, -1e-45)];
+
+        for value in values {
+            assert_eq!(Complex::<f32>::from_le_bytes(value.to_le_bytes()), value);
+            assert_eq!(Complex::<f32>::from_be_bytes(value.to_be_bytes()), value);
+            assert_eq!(Complex::<f32>::from_ne_bytes(value.to_ne_bytes()), value);
+            assert_eq!(Complex::<f32>::try_from_le_bytes(&value.to_le_bytes()), Ok(value));
+        }
+
+        assert_eq!(Complex::<f32>::slice_from_le_bytes(&Complex::<f32>::slice_to_le_bytes(&values)), Ok(values.to_vec()));
+        assert_eq!(Complex::<f32>::slice_from_be_bytes(&Complex::<f32>::slice_to_be_bytes(&values)), Ok(values.to_vec()));
+        assert_eq!(Complex::<f32>::slice_to_be_bytes(&values)[8..16], values[1].to_be_bytes());
+    }
+
+    #[test]
+    fn round_trip_f64() {
+        let values = [Complex::new(0.1f64, -0.0), Complex::new(f64::MIN_POSITIVE, f64::MAX), Complex::new(f64::NEG_INFINITY, 5e-324)];
+
+        for value in values {
+            assert_eq!(Complex::<f64>::from_le_bytes(value.to_le_bytes()), value);
+            assert_eq!(Complex::<f64>::from_be_bytes(value.to_be_bytes()), value);
+            assert_eq!(Complex::<f64>::from_ne_bytes(value.to_ne_bytes()), value);
+            assert_eq!(Complex::<f64>::try_from_be_bytes(&value.to_be_bytes()), Ok(value));
+        }
+
+        assert_eq!(Complex::<f64>::slice_from_le_bytes(&Complex::<f64>::slice_to_le_bytes(&values)), Ok(values.to_vec()));
+        assert_eq!(Complex::<f64>::slice_from_be_bytes(&Complex::<f64>::slice_to_be_bytes(&values)), Ok(values.to_vec()));
+        assert_eq!(Complex::<f64>::slice_from_le_bytes(&[]), Ok(Vec::new()));
+
+        let nan = Complex::<f64>::from_le_bytes(Complex::new(f64::NAN, -f64::NAN).to_le_bytes());
+        assert_eq!(nan.to_array().map(f64::to_bits), [f64::NAN.to_bits(), (-f64::NAN).to_bits()]);
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert_eq!(Complex::<f32>::try_from_le_bytes(&[0; 7]), Err(ByteLengthError::Exact { expected: 8, found: 7 }));
+        assert_eq!(Complex::<f64>::try_from_be_bytes(&[0; 17]), Err(ByteLengthError::Exact { expected: 16, found: 17 }));
+        assert_eq!(Complex::<f64>::try_from_ne_bytes(&[]), Err(ByteLengthError::Exact { expected: 16, found: 0 }));
+        assert_eq!(Complex::<f32>::slice_from_be_bytes(&[0; 12]), Err(ByteLengthError::Multiple { size: 8, found: 12 }));
+        assert_eq!(Complex::<f64>::slice_from_le_bytes(&[0; 24]), Err(ByteLengthError::Multiple { size: 16, found: 24 }));
+
+        assert_eq!(ByteLengthError::Exact { expected: 16, found: 17 }.to_string(), "expected 16 bytes, found 17");
+        assert_eq!(ByteLengthError::Multiple { size: 8, found: 12 }.to_string(), "byte length 12 is not a multiple of 8");
+    }
+}
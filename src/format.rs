@@ -0,0 +1,212 @@
+use super::Complex;
+
+use core::fmt::{self, Alignment, Display, LowerExp, UpperExp};
+
+use num_traits::Float;
+
+
+/// Formats a complex number with `j` as the imaginary unit, e.g. `3+4j`.
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayJ<'a, N: Float>(&'a Complex<N>);
+
+impl<N: Float> Complex<N> {
+    pub fn display_j(&self) -> DisplayJ<'_, N> {
+        return DisplayJ(self);
+    }
+}
+
+// Sign of a part. NaN is printed without a minus sign, as for the underlying float types.
+fn sign<N: Float>(value: N, plus: bool) -> &'static str {
+    if value.is_sign_negative() && !value.is_nan() {
+        return "-";
+    }
+    if plus {
+        return "+";
+    }
+
+    return "";
+}
+
+// `magnitude` formats the absolute value of a part with the requested precision.
+fn write_complex<N: Float, F: Fn(N, Option<usize>) -> String>(value: &Complex<N>, formatter: &mut fmt::Formatter, unit: char, magnitude: F) -> fmt::Result {
+    let precision = formatter.precision();
+    let plus = formatter.sign_plus();
+
+    if formatter.alternate() {
+        let (amplitude, argument) = (value.amplitude(), value.argument());
+        let amplitude = pad(formatter, &format!("{}{}", sign(amplitude, plus), magnitude(amplitude.abs(), precision)));
+        let argument = pad(formatter, &format!("{}{}", sign(argument, false), magnitude(argument.abs(), precision)));
+
+        return write!(formatter, "{amplitude}∠{argument}");
+    }
+
+    let real = pad(formatter, &format!("{}{}", sign(value.real, plus), magnitude(value.real.abs(), precision)));
+    let imaginary = pad(formatter, &format!("{}{}{unit}", sign(value.imaginary, true), magnitude(value.imaginary.abs(), precision)));
+
+    return write!(formatter, "{real}{imaginary}");
+}
+
+// Applies width, fill and alignment to one part. `Formatter::pad` can't be used because it
+// would also truncate the text to the precision.
+fn pad(formatter: &fmt::Formatter, text: &str) -> String {
+    let length = text.chars().count();
+    let width = formatter.width().unwrap_or(0);
+
+    if length >= width {
+        return text.to_string();
+    }
+
+    let padding = width - length;
+
+    if formatter.sign_aware_zero_pad() {
+        let digits = text.trim_start_matches(['+', '-']);
+        let sign = &text[..text.len() - digits.len()];
+
+        return format!("{sign}{}{digits}", "0".repeat(padding));
+    }
+
+    let (before, after) = match formatter.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0)
+    };
+    let fill = formatter.fill().to_string();
+
+    return format!("{}{text}{}", fill.repeat(before), fill.repeat(after));
+}
+
+fn display<N: Float+Display>(value: N, precision: Option<usize>) -> String {
+    return match precision {
+        Some(precision) => format!("{value:.precision$}"),
+        None => format!("{value}")
+    };
+}
+
+fn lower_exp<N: Float+LowerExp>(value: N, precision: Option<usize>) -> String {
+    return match precision {
+        Some(precision) => format!("{value:.precision$e}"),
+        None => format!("{value:e}")
+    };
+}
+
+fn upper_exp<N: Float+UpperExp>(value: N, precision: Option<usize>) -> String {
+    return match precision {
+        Some(precision) => format!("{value:.precision$E}"),
+        None => format!("{value:E}")
+    };
+}
+
+/// Prints `3+4i`, honouring precision and `+`. Width, fill, alignment and `0` apply to each
+/// part separately, the imaginary part counting its sign and unit, so `{:>4}` prints
+/// `   3 +4i`. Without a precision or width the output parses back to the same value bit for
+/// bit.
+///
+/// The alternate form `{:#}` prints the polar form `5∠0.927`. It goes through `cos` and
+/// `sin` when parsed, so it is not exact: `5∠0.9272952180016122` parses back as
+/// `3.0000000000000004+3.9999999999999996i`.
+impl<N: Float+Display> Display for Complex<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write_complex(self, formatter, 'i', display);
+    }
+}
+
+impl<N: Float+LowerExp> LowerExp for Complex<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write_complex(self, formatter, 'i', lower_exp);
+    }
+}
+
+impl<N: Float+UpperExp> UpperExp for Complex<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write_complex(self, formatter, 'i', upper_exp);
+    }
+}
+
+impl<N: Float+Display> Display for DisplayJ<'_, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write_complex(self.0, formatter, 'j', display);
+    }
+}
+
+impl<N: Float+LowerExp> LowerExp for DisplayJ<'_, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write_complex(self.0, formatter, 'j', lower_exp);
+    }
+}
+
+impl<N: Float+UpperExp> UpperExp for DisplayJ<'_, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write_complex(self.0, formatter, 'j', upper_exp);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type C = Complex<f64>;
+
+    #[test]
+    fn display() {
+        let z = C::new(3.0, 4.0);
+
+        assert_eq!(format!("{z}"), "3+4i");
+        assert_eq!(format!("{}", C::new(-1.5, -0.25)), "-1.5-0.25i");
+        assert_eq!(format!("{z:+}"), "+3+4i");
+        assert_eq!(format!("{:+}", C::new(-3.0, 4.0)), "-3+4i");
+        assert_eq!(format!("{z:.2}"), "3.00+4.00i");
+        assert_eq!(format!("{}", z.display_j()), "3+4j");
+    }
+
+    #[test]
+    fn width() {
+        let z = C::new(3.0, 4.0);
+
+        assert_eq!(format!("{z:>20}"), format!("{:>20}{:>20}", "3", "+4i"));
+        assert_eq!(format!("{z:<6}"), "3     +4i   ");
+        assert_eq!(format!("{z:*^20}"), "*********3******************+4i*********");
+        assert_eq!(format!("{z:08.3}"), "0003.000+04.000i");
+        assert_eq!(format!("{:08.3}", C::new(-3.0, -4.0)), "-003.000-04.000i");
+        assert_eq!(format!("{:+06}", z.display_j()), "+00003+0004j");
+        assert_eq!(format!("{z:2}"), " 3+4i");
+    }
+
+    #[test]
+    fn exponent() {
+        let z = C::new(1234.5, -0.00012);
+
+        assert_eq!(format!("{z:e}"), "1.2345e3-1.2e-4i");
+        assert_eq!(format!("{z:.2e}"), "1.23e3-1.20e-4i");
+        assert_eq!(format!("{z:E}"), "1.2345E3-1.2E-4i");
+        assert_eq!(format!("{:E}", C::new(1500.0, 0.25)), "1.5E3+2.5E-1i");
+        assert_eq!(format!("{:>9E}", C::new(1500.0, 0.25)), "    1.5E3 +2.5E-1i");
+        assert_eq!(format!("{:e}", z.display_j()), "1.2345e3-1.2e-4j");
+    }
+
+    #[test]
+    fn polar() {
+        let z = C::new(3.0, 4.0);
+
+        assert_eq!(format!("{z:#}"), "5∠0.9272952180016122");
+        assert_eq!(format!("{z:#.3}"), "5.000∠0.927");
+        assert_eq!(format!("{:#.3}", C::new(0.0, -2.0)), "2.000∠-1.571");
+        assert_eq!(format!("{z:>#7.3}"), "  5.000∠  0.927");
+        assert_eq!(format!("{z:#.1e}"), "5.0e0∠9.3e-1");
+    }
+
+    #[test]
+    fn negative_zero() {
+        assert_eq!(format!("{}", C::new(-0.0, -0.0)), "-0-0i");
+        assert_eq!(format!("{}", C::new(0.0, -0.0)), "0-0i");
+        assert_eq!(format!("{:+}", C::new(0.0, -0.0)), "+0-0i");
+        assert_eq!(format!("{:+.1}", C::new(-0.0, 0.0)), "-0.0+0.0i");
+        assert_eq!(format!("{:05}", C::new(-0.0, -0.0)), "-0000-000i");
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(format!("{}", C::new(f64::NAN, f64::INFINITY)), "NaN+infi");
+        assert_eq!(format!("{}", C::new(f64::NEG_INFINITY, -f64::NAN)), "-inf+NaNi");
+    }
+}
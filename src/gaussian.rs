@@ -0,0 +1,424 @@
+use core::fmt::{self, Debug, Display};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+use num_traits::{One, PrimInt, Signed, Zero};
+
+
+/// Complex number with integer parts, i.e. an element of Z[i].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GaussianInteger<N: PrimInt+Signed> {
+    pub real: N,
+    pub imaginary: N
+}
+
+impl<N: PrimInt+Signed> GaussianInteger<N> {
+    pub const fn new(real: N, imaginary: N) -> Self {
+        return Self {
+            real: real,
+            imaginary: imaginary
+        };
+    }
+
+    pub fn from_real(real: N) -> Self {
+        return Self {
+            real: real,
+            imaginary: N::zero()
+        };
+    }
+
+    pub fn zero() -> Self {
+        return Self {
+            real: N::zero(),
+            imaginary: N::zero()
+        };
+    }
+
+    pub fn one() -> Self {
+        return Self {
+            real: N::one(),
+            imaginary: N::zero()
+        };
+    }
+
+    pub fn i() -> Self {
+        return Self {
+            real: N::zero(),
+            imaginary: N::one()
+        };
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.real.is_zero() && self.imaginary.is_zero();
+    }
+
+    pub fn is_one(&self) -> bool {
+        return self.real.is_one() && self.imaginary.is_zero();
+    }
+
+    /// The units of Z[i] are 1, i, -1 and -i.
+    pub fn is_unit(&self) -> bool {
+        return self.norm().is_one();
+    }
+
+    // -value, panicking on N::MIN instead of wrapping in release builds.
+    fn negate(value: N) -> N {
+        return N::zero().checked_sub(&value).expect("Gaussian integer negation overflows");
+    }
+
+    /// Panics if `imaginary` is `N::MIN`.
+    pub fn conj(&self) -> Self {
+        return Self {
+            real: self.real,
+            imaginary: Self::negate(self.imaginary)
+        };
+    }
+
+    /// Field norm `real^2 + imaginary^2`, which is multiplicative. Overflows if the norm
+    /// doesn't fit in `N`.
+    pub fn norm(&self) -> N {
+        return self.real * self.real + self.imaginary * self.imaginary;
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        return Self {
+            real: self.real + other.real,
+            imaginary: self.imaginary + other.imaginary
+        };
+    }
+
+    pub fn add_assign(&mut self, other: &Self) {
+        *self = Self::add(self, other);
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        return Self {
+            real: self.real - other.real,
+            imaginary: self.imaginary - other.imaginary
+        };
+    }
+
+    pub fn sub_assign(&mut self, other: &Self) {
+        *self = Self::sub(self, other);
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        return Self {
+            real: self.real * other.real - self.imaginary * other.imaginary,
+            imaginary: self.real * other.imaginary + self.imaginary * other.real
+        };
+    }
+
+    pub fn mul_assign(&mut self, other: &Self) {
+        *self = Self::mul(self, other);
+    }
+
+    /// Panics if `imaginary` is `N::MIN`.
+    pub fn mul_i(&self) -> Self {
+        return Self {
+            real: Self::negate(self.imaginary),
+            imaginary: self.real
+        };
+    }
+
+    /// Panics if a component is `N::MIN`.
+    pub fn neg(&self) -> Self {
+        return Self {
+            real: Self::negate(self.real),
+            imaginary: Self::negate(self.imaginary)
+        };
+    }
+
+    // numerator / denominator rounded to the nearest integer, ties upwards, for denominator > 0.
+    fn rounded_division(numerator: i128, denominator: i128) -> i128 {
+        let mut quotient = numerator / denominator;
+        let mut remainder = numerator % denominator;
+
+        if remainder < 0 {
+            quotient -= 1;
+            remainder += denominator;
+        }
+        if remainder >= denominator - remainder {
+            quotient += 1;
+        }
+
+        return quotient;
+    }
+
+    // a * b + c * d, panicking instead of wrapping on overflow.
+    fn wide_dot(a: i128, b: i128, c: i128, d: i128) -> i128 {
+        return a.checked_mul(b).zip(c.checked_mul(d)).and_then(|(x, y)| x.checked_add(y))
+            .expect("Gaussian integer division overflows i128");
+    }
+
+    /// Euclidean division: the quotient is `self / other` rounded to the nearest
+    /// Gaussian integer, so the remainder satisfies `norm(r) <= norm(other) / 2`.
+    ///
+    /// Intermediate products are formed in `i128`, so for `N` up to `i64` every pair of
+    /// operands works, even when their norms overflow `N`. For `i128` the components must
+    /// be below 2^63 in magnitude.
+    ///
+    /// Panics if `other` is zero or the quotient doesn't fit in `N` (e.g. `N::MIN / -1`).
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        let wide = |n: N| n.to_i128().unwrap();
+        let narrow = |n: i128| N::from(n).expect("Gaussian integer quotient overflows");
+        let (a, b) = (wide(self.real), wide(self.imaginary));
+        let (c, d) = (wide(other.real), wide(other.imaginary));
+
+        let norm = Self::wide_dot(c, c, d, d);
+        let (real, imaginary) = (Self::wide_dot(a, c, b, d), Self::wide_dot(b, c, -a, d));
+        let (q, p) = (Self::rounded_division(real, norm), Self::rounded_division(imaginary, norm));
+        let remainder = (a - Self::wide_dot(q, c, -p, d), b - Self::wide_dot(q, d, p, c));
+
+        return (Self::new(narrow(q), narrow(p)), Self::new(narrow(remainder.0), narrow(remainder.1)));
+    }
+
+    pub fn div(&self, other: &Self) -> Self {
+        return Self::div_rem(self, other).0;
+    }
+
+    pub fn div_assign(&mut self, other: &Self) {
+        *self = Self::div(self, other);
+    }
+
+    pub fn rem(&self, other: &Self) -> Self {
+        return Self::div_rem(self, other).1;
+    }
+
+    pub fn rem_assign(&mut self, other: &Self) {
+        *self = Self::rem(self, other);
+    }
+
+    /// The associate of `self` in the first quadrant (`real > 0`, `imaginary >= 0`).
+    /// Zero is returned unchanged.
+    ///
+    /// Panics if a component is `N::MIN`: reaching the first quadrant then means negating it.
+    pub fn normalize(&self) -> Self {
+        let mut value = *self;
+
+        if value.is_zero() {
+            return value;
+        }
+
+        while !(value.real > N::zero() && value.imaginary >= N::zero()) {
+            value = value.mul_i();
+        }
+
+        return value;
+    }
+
+    /// Greatest common divisor, normalized to the first quadrant.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (*self, *other);
+
+        while !b.is_zero() {
+            let remainder = Self::rem(&a, &b);
+            a = b;
+            b = remainder;
+        }
+
+        return a.normalize();
+    }
+}
+
+impl<N: PrimInt+Signed> Default for GaussianInteger<N> {
+    fn default() -> Self {
+        return Self::zero();
+    }
+}
+
+impl<N: PrimInt+Signed> Zero for GaussianInteger<N> {
+    fn zero() -> Self {
+        return Self::zero();
+    }
+
+    fn is_zero(&self) -> bool {
+        return Self::is_zero(self);
+    }
+}
+
+impl<N: PrimInt+Signed> One for GaussianInteger<N> {
+    fn one() -> Self {
+        return Self::one();
+    }
+}
+
+impl<N: PrimInt+Signed> From<(N, N)> for GaussianInteger<N> {
+    fn from((real, imaginary): (N, N)) -> Self {
+        return Self::new(real, imaginary);
+    }
+}
+
+impl<N: PrimInt+Signed> From<N> for GaussianInteger<N> {
+    fn from(real: N) -> Self {
+        return Self::from_real(real);
+    }
+}
+
+impl<N: PrimInt+Signed> Add for GaussianInteger<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        return Self::add(&self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed> AddAssign for GaussianInteger<N> {
+    fn add_assign(&mut self, other: Self) {
+        Self::add_assign(self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed> Div for GaussianInteger<N> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        return Self::div(&self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed> DivAssign for GaussianInteger<N> {
+    fn div_assign(&mut self, other: Self) {
+        Self::div_assign(self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed> Mul for GaussianInteger<N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        return Self::mul(&self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed> MulAssign for GaussianInteger<N> {
+    fn mul_assign(&mut self, other: Self) {
+        Self::mul_assign(self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed> Neg for GaussianInteger<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        return Self::neg(&self);
+    }
+}
+
+impl<N: PrimInt+Signed> Rem for GaussianInteger<N> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self::Output {
+        return Self::rem(&self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed> RemAssign for GaussianInteger<N> {
+    fn rem_assign(&mut self, other: Self) {
+        Self::rem_assign(self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed> Sub for GaussianInteger<N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        return Self::sub(&self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed> SubAssign for GaussianInteger<N> {
+    fn sub_assign(&mut self, other: Self) {
+        Self::sub_assign(self, &other);
+    }
+}
+
+impl<N: PrimInt+Signed+Debug> Debug for GaussianInteger<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write!(formatter, "GaussianInteger {{ real: {:?}, imaginary: {:?}i }}", self.real, self.imaginary);
+    }
+}
+
+impl<N: PrimInt+Signed+Display> Display for GaussianInteger<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.imaginary.is_negative() { '-' } else { '+' };
+
+        return write!(formatter, "{}{sign}{}i", self.real, self.imaginary.abs());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_div_rem<N: PrimInt+Signed+Debug>(a: GaussianInteger<N>, b: GaussianInteger<N>) {
+        let wide = |n: N| n.to_i128().unwrap();
+        let (q, r) = a.div_rem(&b);
+        let (c, d) = (wide(b.real), wide(b.imaginary));
+
+        assert_eq!(wide(a.real), wide(q.real) * c - wide(q.imaginary) * d + wide(r.real));
+        assert_eq!(wide(a.imaginary), wide(q.real) * d + wide(q.imaginary) * c + wide(r.imaginary));
+        assert!(2 * (wide(r.real).pow(2) + wide(r.imaginary).pow(2)) <= c * c + d * d);
+    }
+
+    #[test]
+    fn div_rem() {
+        type G = GaussianInteger<i32>;
+
+        assert_eq!(G::new(60000, 0).div_rem(&G::new(40000, 0)), (G::new(2, 0), G::new(-20000, 0)));
+        assert_eq!(G::new(7, 3).div_rem(&G::new(2, 0)), (G::new(4, 2), G::new(-1, -1)));
+        check_div_rem(G::new(2_000_000_000, -1_500_000_000), G::new(1_000_000_007, 999_999_937));
+        check_div_rem(G::new(i32::MAX, i32::MIN), G::new(i32::MIN, i32::MAX));
+        check_div_rem(G::new(-5, 9), G::new(1, -1));
+        check_div_rem(GaussianInteger::new(i64::MAX, i64::MIN + 1), GaussianInteger::new(i64::MAX - 1, 3));
+        check_div_rem(GaussianInteger::new(i128::from(i64::MAX), -1), GaussianInteger::new(-3, i128::from(i64::MIN) + 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_rem_zero() {
+        let _ = GaussianInteger::new(1, 1).div_rem(&GaussianInteger::zero());
+    }
+
+    #[test]
+    fn gcd() {
+        type G = GaussianInteger<i32>;
+
+        assert_eq!(G::new(60000, 0).gcd(&G::new(40000, 0)), G::new(20000, 0));
+        assert_eq!(G::new(0, 0).gcd(&G::new(0, -3)), G::new(3, 0));
+
+        let g = G::new(123, 45);
+        let a = G::mul(&g, &G::new(101, -7));
+        let b = G::mul(&g, &G::new(-3, 88));
+        assert_eq!(a.gcd(&b), g);
+    }
+
+    #[test]
+    fn normalize() {
+        type G = GaussianInteger<i64>;
+
+        assert_eq!(G::new(-3, 2).normalize(), G::new(2, 3));
+        assert_eq!(G::new(0, -4).normalize(), G::new(4, 0));
+        assert_eq!(G::zero().normalize(), G::zero());
+        assert_eq!(G::new(i64::MIN + 1, 0).normalize(), G::new(i64::MAX, 0));
+        assert_eq!(G::new(0, i64::MIN + 1).normalize(), G::new(i64::MAX, 0));
+        assert_eq!(G::new(i64::MIN, 0).mul_i(), G::new(0, i64::MIN));
+    }
+
+    #[test]
+    #[should_panic(expected = "negation overflows")]
+    fn normalize_min() {
+        let _ = GaussianInteger::new(i32::MIN, 0).normalize();
+    }
+
+    #[test]
+    #[should_panic(expected = "negation overflows")]
+    fn mul_i_min() {
+        let _ = GaussianInteger::new(3, i8::MIN).mul_i();
+    }
+
+    #[test]
+    #[should_panic(expected = "negation overflows")]
+    fn neg_min() {
+        let _ = -GaussianInteger::new(i16::MIN, 1);
+    }
+}
@@ -0,0 +1,217 @@
+use super::Complex;
+
+use core::fmt::{self, Display};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_traits::Float;
+
+
+/// Purely imaginary number, the coefficient of i.
+///
+/// Lets formulas be written with literals, e.g. `3.0 + 4.0 * I` is a `Complex<f64>`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Imaginary<N: Float>(pub N);
+
+/// The imaginary unit.
+pub const I: Imaginary<f64> = Imaginary(1.0);
+
+impl<N: Float> Imaginary<N> {
+    pub const fn new(value: N) -> Self {
+        return Self(value);
+    }
+
+    pub fn to_complex(&self) -> Complex<N> {
+        return Complex::new(N::zero(), self.0);
+    }
+}
+
+impl<N: Float> From<Imaginary<N>> for Complex<N> {
+    fn from(value: Imaginary<N>) -> Self {
+        return value.to_complex();
+    }
+}
+
+impl<N: Float> Add for Imaginary<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        return Self(self.0 + other.0);
+    }
+}
+
+impl<N: Float> Add<N> for Imaginary<N> {
+    type Output = Complex<N>;
+
+    fn add(self, other: N) -> Self::Output {
+        return Complex::new(other, self.0);
+    }
+}
+
+impl<N: Float> Add<Complex<N>> for Imaginary<N> {
+    type Output = Complex<N>;
+
+    fn add(self, other: Complex<N>) -> Self::Output {
+        return Complex::new(other.real, self.0 + other.imaginary);
+    }
+}
+
+impl<N: Float> Add<Imaginary<N>> for Complex<N> {
+    type Output = Self;
+
+    fn add(self, other: Imaginary<N>) -> Self::Output {
+        return Self::new(self.real, self.imaginary + other.0);
+    }
+}
+
+impl<N: Float> Div for Imaginary<N> {
+    type Output = N;
+
+    fn div(self, other: Self) -> Self::Output {
+        return self.0 / other.0;
+    }
+}
+
+impl<N: Float> Div<N> for Imaginary<N> {
+    type Output = Self;
+
+    fn div(self, other: N) -> Self::Output {
+        return Self(self.0 / other);
+    }
+}
+
+impl<N: Float> Div<Imaginary<N>> for Complex<N> {
+    type Output = Self;
+
+    // (a + bi) / ci = b/c - (a/c)i
+    fn div(self, other: Imaginary<N>) -> Self::Output {
+        return Self::new(self.imaginary / other.0, -(self.real / other.0));
+    }
+}
+
+impl<N: Float> Mul for Imaginary<N> {
+    type Output = N;
+
+    fn mul(self, other: Self) -> Self::Output {
+        return -(self.0 * other.0);
+    }
+}
+
+impl<N: Float> Mul<N> for Imaginary<N> {
+    type Output = Self;
+
+    fn mul(self, other: N) -> Self::Output {
+        return Self(self.0 * other);
+    }
+}
+
+impl<N: Float> Mul<Complex<N>> for Imaginary<N> {
+    type Output = Complex<N>;
+
+    fn mul(self, other: Complex<N>) -> Self::Output {
+        return Complex::new(-(other.imaginary * self.0), other.real * self.0);
+    }
+}
+
+impl<N: Float> Mul<Imaginary<N>> for Complex<N> {
+    type Output = Self;
+
+    fn mul(self, other: Imaginary<N>) -> Self::Output {
+        return Imaginary::mul(other, self);
+    }
+}
+
+impl<N: Float> Neg for Imaginary<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        return Self(-self.0);
+    }
+}
+
+impl<N: Float> Sub for Imaginary<N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        return Self(self.0 - other.0);
+    }
+}
+
+impl<N: Float> Sub<N> for Imaginary<N> {
+    type Output = Complex<N>;
+
+    fn sub(self, other: N) -> Self::Output {
+        return Complex::new(-other, self.0);
+    }
+}
+
+impl<N: Float> Sub<Complex<N>> for Imaginary<N> {
+    type Output = Complex<N>;
+
+    fn sub(self, other: Complex<N>) -> Self::Output {
+        return Complex::new(-other.real, self.0 - other.imaginary);
+    }
+}
+
+impl<N: Float> Sub<Imaginary<N>> for Complex<N> {
+    type Output = Self;
+
+    fn sub(self, other: Imaginary<N>) -> Self::Output {
+        return Self::new(self.real, self.imaginary - other.0);
+    }
+}
+
+impl<N: Float+Display> Display for Imaginary<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write!(formatter, "{}i", self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type C = Complex<f64>;
+
+    #[test]
+    fn literals() {
+        let z: Complex<f64> = 3.0 + 4.0 * I;
+
+        assert_eq!(z, C::new(3.0, 4.0));
+        assert_eq!(1.5 - 2.0 * I, C::new(1.5, -2.0));
+        assert_eq!(I * 2.0 + 1.0, C::new(1.0, 2.0));
+        assert_eq!(-I - 1.0, C::new(-1.0, -1.0));
+        assert_eq!(2.0f32 * Imaginary(1.0f32) + 1.0f32, Complex::new(1.0f32, 2.0));
+        assert_eq!(C::ZERO + I, C::I);
+        assert_eq!((C::ZERO, C::ONE, C::I), (C::zero(), C::one(), C::i()));
+    }
+
+    #[test]
+    fn imaginary_arithmetic() {
+        // Products and quotients of two imaginary numbers are real.
+        let product: f64 = I * I;
+        assert_eq!(product, -1.0);
+        assert_eq!(Imaginary(2.0) * Imaginary(3.0), -6.0);
+        assert_eq!(Imaginary(6.0) / Imaginary(3.0), 2.0);
+        assert_eq!(Imaginary(2.0) + Imaginary(3.0), Imaginary(5.0));
+        assert_eq!(Imaginary(2.0) - Imaginary(3.0), Imaginary(-1.0));
+        assert_eq!(Imaginary(6.0) / 3.0, Imaginary(2.0));
+        assert_eq!(1.0 / I, Imaginary(-1.0));
+        assert_eq!(6.0 / Imaginary(3.0), Imaginary(-2.0));
+    }
+
+    #[test]
+    fn complex_operands() {
+        let z = C::new(3.0, 4.0);
+
+        assert_eq!(z / Imaginary(2.0), C::new(2.0, -1.5));
+        assert_eq!(z / I, Complex::div(&z, &C::I));
+        assert_eq!(z * Imaginary(2.0), C::new(-8.0, 6.0));
+        assert_eq!(Imaginary(2.0) * z, C::new(-8.0, 6.0));
+        assert_eq!(z + I, C::new(3.0, 5.0));
+        assert_eq!(I + z, C::new(3.0, 5.0));
+        assert_eq!(z - I, C::new(3.0, 3.0));
+        assert_eq!(I - z, C::new(-3.0, -3.0));
+        assert_eq!(C::from(Imaginary(2.5)), C::new(0.0, 2.5));
+        assert_eq!(format!("{}", Imaginary(-1.5)), "-1.5i");
+    }
+}
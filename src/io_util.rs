@@ -0,0 +1,22 @@
+// Helpers shared by the file format readers.
+
+use std::io::{self, Read};
+
+
+pub(crate) fn invalid_data(message: &str) -> io::Error {
+    return io::Error::new(io::ErrorKind::InvalidData, message);
+}
+
+/// Reads exactly `length` bytes. The buffer grows with the data actually read, so a
+/// corrupt length field can't trigger a huge allocation; a short input is `InvalidData`.
+pub(crate) fn read_length<R: Read>(reader: R, length: u64) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+
+    reader.take(length).read_to_end(&mut bytes)?;
+
+    if bytes.len() as u64 != length {
+        return Err(invalid_data("unexpected end of input"));
+    }
+
+    return Ok(bytes);
+}
@@ -0,0 +1,633 @@
+use super::Complex;
+use super::json::Json;
+use super::io_util::invalid_data;
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+
+/// Sample format of an interleaved little-endian IQ recording.
+///
+/// Integer formats are scaled to [-1, 1): `cs16` by 1/32768, `cs8` by 1/128, and `cu8`
+/// (as produced by RTL-SDR dongles) is offset by 127.5 and scaled by 1/127.5.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IqFormat {
+    Cf32,
+    Cf64,
+    Cs16,
+    Cs8,
+    Cu8
+}
+
+impl IqFormat {
+    /// Size of one complex sample in bytes.
+    pub fn sample_size(&self) -> usize {
+        return match self {
+            IqFormat::Cf32 => 8,
+            IqFormat::Cf64 => 16,
+            IqFormat::Cs16 => 4,
+            IqFormat::Cs8 | IqFormat::Cu8 => 2
+        };
+    }
+
+    pub fn extension(&self) -> &'static str {
+        return match self {
+            IqFormat::Cf32 => "cf32",
+            IqFormat::Cf64 => "cf64",
+            IqFormat::Cs16 => "cs16",
+            IqFormat::Cs8 => "cs8",
+            IqFormat::Cu8 => "cu8"
+        };
+    }
+
+    /// Also accepts the `fc32`/`sc16`-style names used by some tools.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        return match extension.to_ascii_lowercase().as_str() {
+            "cf32" | "fc32" | "cfile" => Some(IqFormat::Cf32),
+            "cf64" | "fc64" => Some(IqFormat::Cf64),
+            "cs16" | "sc16" => Some(IqFormat::Cs16),
+            "cs8" | "sc8" => Some(IqFormat::Cs8),
+            "cu8" | "uc8" => Some(IqFormat::Cu8),
+            _ => None
+        };
+    }
+
+    pub fn sigmf_datatype(&self) -> &'static str {
+        return match self {
+            IqFormat::Cf32 => "cf32_le",
+            IqFormat::Cf64 => "cf64_le",
+            IqFormat::Cs16 => "ci16_le",
+            IqFormat::Cs8 => "ci8",
+            IqFormat::Cu8 => "cu8"
+        };
+    }
+
+    pub fn from_sigmf_datatype(datatype: &str) -> Option<Self> {
+        return match datatype {
+            "cf32_le" => Some(IqFormat::Cf32),
+            "cf64_le" => Some(IqFormat::Cf64),
+            "ci16_le" => Some(IqFormat::Cs16),
+            "ci8" => Some(IqFormat::Cs8),
+            "cu8" => Some(IqFormat::Cu8),
+            _ => None
+        };
+    }
+
+    fn decode(&self, bytes: &[u8], samples: &mut Vec<Complex<f32>>) {
+        let size = self.sample_size();
+
+        samples.extend(bytes.chunks_exact(size).map(|sample| {
+            let (real, imaginary) = sample.split_at(size / 2);
+
+            return match self {
+                IqFormat::Cf32 => Complex::new(f32::from_le_bytes(real.try_into().unwrap()), f32::from_le_bytes(imaginary.try_into().unwrap())),
+                IqFormat::Cf64 => Complex::new(f64::from_le_bytes(real.try_into().unwrap()) as f32, f64::from_le_bytes(imaginary.try_into().unwrap()) as f32),
+                IqFormat::Cs16 => Complex::new(
+                    i16::from_le_bytes(real.try_into().unwrap()) as f32 / 32768.0,
+                    i16::from_le_bytes(imaginary.try_into().unwrap()) as f32 / 32768.0
+                ),
+                IqFormat::Cs8 => Complex::new(real[0] as i8 as f32 / 128.0, imaginary[0] as i8 as f32 / 128.0),
+                IqFormat::Cu8 => Complex::new((real[0] as f32 - 127.5) / 127.5, (imaginary[0] as f32 - 127.5) / 127.5)
+            };
+        }));
+    }
+
+    // Integer formats round to nearest and saturate.
+    fn encode(&self, samples: &[Complex<f32>], bytes: &mut Vec<u8>) {
+        for sample in samples {
+            for part in [sample.real, sample.imaginary] {
+                match self {
+                    IqFormat::Cf32 => bytes.extend_from_slice(&part.to_le_bytes()),
+                    IqFormat::Cf64 => bytes.extend_from_slice(&(part as f64).to_le_bytes()),
+                    IqFormat::Cs16 => bytes.extend_from_slice(&((part * 32768.0).round() as i16).to_le_bytes()),
+                    IqFormat::Cs8 => bytes.push((part * 128.0).round() as i8 as u8),
+                    IqFormat::Cu8 => bytes.push((part * 127.5 + 127.5).round() as u8)
+                }
+            }
+        }
+    }
+}
+
+/// Streaming reader converting IQ samples to `Complex<f32>`.
+///
+/// Reads go straight to the underlying reader, so wrap unbuffered sources in a `BufReader`
+/// when reading small chunks.
+pub struct IqReader<R: Read> {
+    reader: R,
+    format: IqFormat,
+    buffer: Vec<u8>,
+    // Set when the input ended inside a sample; reported by the next read.
+    truncated: bool
+}
+
+impl<R: Read> IqReader<R> {
+    pub fn new(reader: R, format: IqFormat) -> Self {
+        return Self {
+            reader: reader,
+            format: format,
+            buffer: Vec::new(),
+            truncated: false
+        };
+    }
+
+    pub fn format(&self) -> IqFormat {
+        return self.format;
+    }
+
+    pub fn into_inner(self) -> R {
+        return self.reader;
+    }
+
+    /// Replaces the contents of `samples` with up to `count` samples and returns how many
+    /// were read; 0 means the end of the input. If the input ends inside a sample, the
+    /// complete samples before it are returned first and the next read fails with
+    /// `UnexpectedEof`.
+    pub fn read_samples(&mut self, samples: &mut Vec<Complex<f32>>, count: usize) -> io::Result<usize> {
+        samples.clear();
+        self.take_truncation()?;
+
+        let size = self.format.sample_size();
+        let wanted = count.checked_mul(size).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "sample count too large"))?;
+        let mut filled = 0;
+
+        self.buffer.resize(wanted, 0);
+
+        while filled < wanted {
+            match self.reader.read(&mut self.buffer[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error)
+            }
+        }
+
+        // The loop only stops short at the end of the input, so a partial sample is never
+        // completed by a later read.
+        self.truncated = filled % size != 0;
+        self.format.decode(&self.buffer[..filled], samples);
+
+        if samples.is_empty() {
+            self.take_truncation()?;
+        }
+
+        return Ok(samples.len());
+    }
+
+    /// Reads every remaining complete sample. As with `read_samples`, a trailing partial
+    /// sample is reported by the next read, or by this one if there are no complete samples.
+    pub fn read_to_end(&mut self) -> io::Result<Vec<Complex<f32>>> {
+        let mut bytes = Vec::new();
+
+        self.take_truncation()?;
+        self.reader.read_to_end(&mut bytes)?;
+        self.truncated = bytes.len() % self.format.sample_size() != 0;
+
+        if bytes.len() < self.format.sample_size() {
+            self.take_truncation()?;
+        }
+
+        let mut samples = Vec::with_capacity(bytes.len() / self.format.sample_size());
+        self.format.decode(&bytes, &mut samples);
+
+        return Ok(samples);
+    }
+
+    /// Whether the input ended inside a sample that has not been reported by a read yet.
+    pub fn is_truncated(&self) -> bool {
+        return self.truncated;
+    }
+
+    fn take_truncation(&mut self) -> io::Result<()> {
+        if std::mem::take(&mut self.truncated) {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "input ends inside an IQ sample"));
+        }
+
+        return Ok(());
+    }
+
+    /// Iterates over the remaining samples in chunks of `count`; only the last chunk may
+    /// be shorter.
+    pub fn chunks(self, count: usize) -> IqChunks<R> {
+        return IqChunks {
+            reader: self,
+            count: count,
+            done: false
+        };
+    }
+}
+
+impl<R: Read+Seek> IqReader<R> {
+    /// Moves to the sample at `index`, counted from the start of the input.
+    pub fn seek_sample(&mut self, index: u64) -> io::Result<()> {
+        let offset = index.checked_mul(self.format.sample_size() as u64).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "sample index too large"))?;
+
+        self.reader.seek(SeekFrom::Start(offset))?;
+
+        return Ok(());
+    }
+
+    /// Number of whole samples in the input.
+    pub fn sample_count(&mut self) -> io::Result<u64> {
+        let position = self.reader.stream_position()?;
+        let length = self.reader.seek(SeekFrom::End(0))?;
+
+        self.reader.seek(SeekFrom::Start(position))?;
+
+        return Ok(length / self.format.sample_size() as u64);
+    }
+}
+
+pub struct IqChunks<R: Read> {
+    reader: IqReader<R>,
+    count: usize,
+    done: bool
+}
+
+impl<R: Read> Iterator for IqChunks<R> {
+    type Item = io::Result<Vec<Complex<f32>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.count == 0 {
+            return None;
+        }
+
+        let mut samples = Vec::with_capacity(self.count);
+
+        return match self.reader.read_samples(&mut samples, self.count) {
+            Ok(0) => {
+                self.done = true;
+                None
+            },
+            Ok(_) => Some(Ok(samples)),
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        };
+    }
+}
+
+/// Streaming writer converting `Complex<f32>` samples to an IQ format.
+pub struct IqWriter<W: Write> {
+    writer: W,
+    format: IqFormat,
+    buffer: Vec<u8>
+}
+
+impl<W: Write> IqWriter<W> {
+    pub fn new(writer: W, format: IqFormat) -> Self {
+        return Self {
+            writer: writer,
+            format: format,
+            buffer: Vec::new()
+        };
+    }
+
+    pub fn format(&self) -> IqFormat {
+        return self.format;
+    }
+
+    pub fn write_samples(&mut self, samples: &[Complex<f32>]) -> io::Result<()> {
+        self.buffer.clear();
+        self.format.encode(samples, &mut self.buffer);
+
+        return self.writer.write_all(&self.buffer);
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        return self.writer.flush();
+    }
+
+    pub fn into_inner(self) -> W {
+        return self.writer;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigMfCapture {
+    pub sample_start: u64,
+    /// Center frequency in Hz.
+    pub frequency: Option<f64>,
+    /// ISO 8601 timestamp of the first sample.
+    pub datetime: Option<String>
+}
+
+impl SigMfCapture {
+    pub fn new(sample_start: u64) -> Self {
+        return Self {
+            sample_start: sample_start,
+            frequency: None,
+            datetime: None
+        };
+    }
+}
+
+/// The core fields of a SigMF `.sigmf-meta` file. Annotations and other keys are not kept.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigMfMetadata {
+    pub datatype: IqFormat,
+    /// Sample rate in Hz.
+    pub sample_rate: Option<f64>,
+    pub version: String,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub hw: Option<String>,
+    pub captures: Vec<SigMfCapture>
+}
+
+impl SigMfMetadata {
+    pub fn new(datatype: IqFormat) -> Self {
+        return Self {
+            datatype: datatype,
+            sample_rate: None,
+            version: "1.0.0".to_owned(),
+            description: None,
+            author: None,
+            hw: None,
+            captures: vec![SigMfCapture::new(0)]
+        };
+    }
+
+    pub fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+
+        let json = Json::parse(&text)?;
+        let global = json.get("global").ok_or_else(|| invalid_data("SigMF metadata has no global object"))?;
+        let string = |object: &Json, key: &str| object.get(key).and_then(Json::as_str).map(str::to_owned);
+
+        let datatype = global.get("core:datatype").and_then(Json::as_str).ok_or_else(|| invalid_data("SigMF metadata has no core:datatype"))?;
+        let datatype = IqFormat::from_sigmf_datatype(datatype).ok_or_else(|| invalid_data("unsupported SigMF datatype"))?;
+        let mut captures = Vec::new();
+
+        for capture in json.get("captures").and_then(Json::as_array).unwrap_or_default() {
+            captures.push(SigMfCapture {
+                sample_start: capture.get("core:sample_start").and_then(Json::as_f64).unwrap_or(0.0) as u64,
+                frequency: capture.get("core:frequency").and_then(Json::as_f64),
+                datetime: string(capture, "core:datetime")
+            });
+        }
+
+        return Ok(Self {
+            datatype: datatype,
+            sample_rate: global.get("core:sample_rate").and_then(Json::as_f64),
+            version: string(global, "core:version").unwrap_or_default(),
+            description: string(global, "core:description"),
+            author: string(global, "core:author"),
+            hw: string(global, "core:hw"),
+            captures: captures
+        });
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut global = vec![
+            ("core:datatype".to_owned(), Json::String(self.datatype.sigmf_datatype().to_owned())),
+            ("core:version".to_owned(), Json::String(self.version.clone()))
+        ];
+
+        if let Some(sample_rate) = self.sample_rate {
+            global.push(("core:sample_rate".to_owned(), Json::Number(sample_rate)));
+        }
+
+        for (key, value) in [("core:description", &self.description), ("core:author", &self.author), ("core:hw", &self.hw)] {
+            if let Some(value) = value {
+                global.push((key.to_owned(), Json::String(value.clone())));
+            }
+        }
+
+        let captures = self.captures.iter().map(|capture| {
+            let mut members = vec![("core:sample_start".to_owned(), Json::Number(capture.sample_start as f64))];
+
+            if let Some(frequency) = capture.frequency {
+                members.push(("core:frequency".to_owned(), Json::Number(frequency)));
+            }
+            if let Some(datetime) = &capture.datetime {
+                members.push(("core:datetime".to_owned(), Json::String(datetime.clone())));
+            }
+
+            return Json::Object(members);
+        }).collect();
+
+        let json = Json::Object(vec![
+            ("global".to_owned(), Json::Object(global)),
+            ("captures".to_owned(), Json::Array(captures)),
+            ("annotations".to_owned(), Json::Array(Vec::new()))
+        ]);
+
+        writer.write_all(json.to_pretty_string().as_bytes())?;
+
+        return writer.write_all(b"\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    const FORMATS: [IqFormat; 5] = [IqFormat::Cf32, IqFormat::Cf64, IqFormat::Cs16, IqFormat::Cs8, IqFormat::Cu8];
+
+    fn encode(format: IqFormat, samples: &[Complex<f32>]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        format.encode(samples, &mut bytes);
+
+        return bytes;
+    }
+
+    fn decode(format: IqFormat, bytes: &[u8]) -> Vec<Complex<f32>> {
+        let mut samples = Vec::new();
+        format.decode(bytes, &mut samples);
+
+        return samples;
+    }
+
+    fn write(metadata: &SigMfMetadata) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        metadata.write(&mut bytes).unwrap();
+
+        return bytes;
+    }
+
+    #[test]
+    fn names() {
+        for format in FORMATS {
+            assert_eq!(IqFormat::from_extension(format.extension()), Some(format));
+            assert_eq!(IqFormat::from_sigmf_datatype(format.sigmf_datatype()), Some(format));
+        }
+
+        assert_eq!(IqFormat::from_extension("FC32"), Some(IqFormat::Cf32));
+        assert_eq!(IqFormat::from_extension("wav"), None);
+    }
+
+    #[test]
+    fn float_formats() {
+        let samples = [Complex::new(0.25f32, -1.5), Complex::new(f32::MAX, -0.0), Complex::new(1e-40, f32::INFINITY)];
+
+        for format in [IqFormat::Cf32, IqFormat::Cf64] {
+            let bytes = encode(format, &samples);
+
+            assert_eq!(bytes.len(), samples.len() * format.sample_size());
+            assert_eq!(decode(format, &bytes), samples);
+        }
+
+        assert_eq!(encode(IqFormat::Cf32, &samples[..1]), [0.25f32.to_le_bytes(), (-1.5f32).to_le_bytes()].concat());
+    }
+
+    #[test]
+    fn cs16() {
+        let format = IqFormat::Cs16;
+
+        assert_eq!(encode(format, &[Complex::new(0.5, -1.0)]), [0x00, 0x40, 0x00, 0x80]);
+        assert_eq!(decode(format, &[0x00, 0x40, 0x00, 0x80]), [Complex::new(0.5, -1.0)]);
+        // Values outside [-1, 1) saturate.
+        assert_eq!(encode(format, &[Complex::new(1.0, -2.0)]), [0xff, 0x7f, 0x00, 0x80]);
+        assert_eq!(decode(format, &[0xff, 0x7f, 0x01, 0x00]), [Complex::new(32767.0 / 32768.0, 1.0 / 32768.0)]);
+    }
+
+    #[test]
+    fn cs8() {
+        let format = IqFormat::Cs8;
+
+        assert_eq!(encode(format, &[Complex::new(0.5, -1.0)]), [64, 0x80]);
+        assert_eq!(decode(format, &[64, 0x80]), [Complex::new(0.5, -1.0)]);
+        assert_eq!(encode(format, &[Complex::new(1.0, -3.0)]), [127, 0x80]);
+        assert_eq!(encode(format, &[Complex::new(0.004, -0.003)]), [1, 0]);
+    }
+
+    #[test]
+    fn cu8() {
+        let format = IqFormat::Cu8;
+
+        // The 127.5 offset puts zero between two codes, and the extremes map to ±1.
+        assert_eq!(decode(format, &[0, 255]), [Complex::new(-1.0, 1.0)]);
+        assert_eq!(decode(format, &[127, 128]), [Complex::new(-0.5 / 127.5, 0.5 / 127.5)]);
+        assert_eq!(encode(format, &[Complex::new(-1.0, 1.0)]), [0, 255]);
+        assert_eq!(encode(format, &[Complex::new(0.0, 0.5 / 127.5)]), [128, 128]);
+        assert_eq!(encode(format, &[Complex::new(-0.5 / 127.5, 0.25)]), [127, 159]);
+        // Values outside [-1, 1] saturate instead of wrapping.
+        assert_eq!(encode(format, &[Complex::new(2.0, -2.0)]), [255, 0]);
+        assert_eq!(encode(format, &[Complex::new(f32::INFINITY, f32::NEG_INFINITY)]), [255, 0]);
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(encode(format, &decode(format, &bytes)), bytes);
+    }
+
+    #[test]
+    fn reader_writer() {
+        let samples: Vec<_> = (0..10).map(|i| Complex::new(i as f32 / 16.0, -(i as f32) / 32.0)).collect();
+
+        for format in FORMATS {
+            let mut writer = IqWriter::new(Vec::new(), format);
+            writer.write_samples(&samples[..4]).unwrap();
+            writer.write_samples(&samples[4..]).unwrap();
+            writer.flush().unwrap();
+
+            let bytes = writer.into_inner();
+            let mut reader = IqReader::new(Cursor::new(bytes), format);
+            assert_eq!(reader.sample_count().unwrap(), 10);
+
+            let decoded = reader.read_to_end().unwrap();
+            assert_eq!(decoded.len(), 10);
+
+            for (decoded, sample) in decoded.iter().zip(&samples) {
+                assert!((decoded - sample).norm() <= 1.0 / 127.5, "{format:?}: {decoded} != {sample}");
+            }
+
+            reader.seek_sample(7).unwrap();
+            let mut tail = Vec::new();
+            assert_eq!(reader.read_samples(&mut tail, 5).unwrap(), 3);
+            assert_eq!(tail, decoded[7..]);
+            assert_eq!(reader.read_samples(&mut tail, 5).unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn chunks() {
+        let samples: Vec<_> = (0..7).map(|i| Complex::new(i as f32, 0.5)).collect();
+        let bytes = encode(IqFormat::Cf32, &samples);
+
+        let chunks: Vec<_> = IqReader::new(bytes.as_slice(), IqFormat::Cf32).chunks(3).map(Result::unwrap).collect();
+        assert_eq!(chunks, [&samples[..3], &samples[3..6], &samples[6..]]);
+
+        assert_eq!(IqReader::new(bytes.as_slice(), IqFormat::Cf32).chunks(0).count(), 0);
+        assert_eq!(IqReader::new(&bytes[..0], IqFormat::Cf32).chunks(3).count(), 0);
+
+        // The complete samples before a truncated final sample are delivered, then the
+        // truncation is reported once and iteration stops.
+        let mut chunks = IqReader::new(&bytes[..bytes.len() - 1], IqFormat::Cf32).chunks(4);
+        assert_eq!(chunks.next().unwrap().unwrap(), samples[..4]);
+        assert_eq!(chunks.next().unwrap().unwrap(), samples[4..6]);
+        assert_eq!(chunks.next().unwrap().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert!(chunks.next().is_none());
+
+        let mut chunks = IqReader::new(&bytes[..bytes.len() - 1], IqFormat::Cf32).chunks(3);
+        assert_eq!(chunks.next().unwrap().unwrap(), samples[..3]);
+        assert_eq!(chunks.next().unwrap().unwrap(), samples[3..6]);
+        assert_eq!(chunks.next().unwrap().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn truncated() {
+        let samples: Vec<_> = (0..3).map(|i| Complex::new(i as f32, -0.5)).collect();
+        let bytes = encode(IqFormat::Cs16, &samples);
+        let truncated = &bytes[..bytes.len() - 3];
+
+        let mut reader = IqReader::new(truncated, IqFormat::Cs16);
+        let mut chunk = Vec::new();
+        assert_eq!(reader.read_samples(&mut chunk, 10).unwrap(), 2);
+        assert_eq!(chunk, decode(IqFormat::Cs16, &bytes[..8]));
+        assert!(reader.is_truncated());
+        assert_eq!(reader.read_samples(&mut chunk, 10).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert!(chunk.is_empty());
+        assert!(!reader.is_truncated());
+        assert_eq!(reader.read_samples(&mut chunk, 10).unwrap(), 0);
+
+        let mut reader = IqReader::new(truncated, IqFormat::Cs16);
+        assert_eq!(reader.read_to_end().unwrap(), decode(IqFormat::Cs16, &bytes[..8]));
+        assert_eq!(reader.read_to_end().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(reader.read_to_end().unwrap(), []);
+
+        // Without a complete sample the truncation is reported straight away.
+        let mut reader = IqReader::new(&bytes[..3], IqFormat::Cs16);
+        assert_eq!(reader.read_to_end().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        let mut reader = IqReader::new(&bytes[..3], IqFormat::Cs16);
+        assert_eq!(reader.read_samples(&mut chunk, 1).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(reader.read_samples(&mut chunk, 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn sigmf_round_trip() {
+        let mut metadata = SigMfMetadata::new(IqFormat::Cs16);
+        assert_eq!(SigMfMetadata::read(write(&metadata).as_slice()).unwrap(), metadata);
+
+        metadata.sample_rate = Some(2.4e6);
+        metadata.description = Some("FM band \"sweep\"\n\u{e9}\u{1f4e1}".to_owned());
+        metadata.author = Some("test".to_owned());
+        metadata.hw = Some("rtl-sdr\\v3".to_owned());
+        metadata.captures = vec![
+            SigMfCapture {
+                sample_start: 0,
+                frequency: Some(100.1e6),
+                datetime: Some("2024-01-01T00:00:00Z".to_owned())
+            },
+            SigMfCapture::new(1 << 40)
+        ];
+
+        for format in FORMATS {
+            metadata.datatype = format;
+
+            assert_eq!(SigMfMetadata::read(write(&metadata).as_slice()).unwrap(), metadata);
+        }
+    }
+
+    #[test]
+    fn sigmf_errors() {
+        let read = |text: &str| SigMfMetadata::read(text.as_bytes()).unwrap_err().kind();
+
+        assert_eq!(read("{}"), io::ErrorKind::InvalidData);
+        assert_eq!(read(r#"{"global": {"core:datatype": "ri16_le"}}"#), io::ErrorKind::InvalidData);
+        assert_eq!(read(r#"{"global": {"core:datatype": "cf32_le"}"#), io::ErrorKind::InvalidData);
+        assert_eq!(read(&"[".repeat(200000)), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,352 @@
+// Just enough JSON to read and write SigMF metadata files.
+
+use super::io_util::invalid_data;
+
+use std::io;
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>)
+}
+
+impl Json {
+    pub(crate) fn get(&self, key: &str) -> Option<&Json> {
+        return match self {
+            Json::Object(members) => members.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+            _ => None
+        };
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        return match self {
+            Json::String(value) => Some(value),
+            _ => None
+        };
+    }
+
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        return match self {
+            Json::Number(value) => Some(*value),
+            _ => None
+        };
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[Json]> {
+        return match self {
+            Json::Array(values) => Some(values),
+            _ => None
+        };
+    }
+
+    pub(crate) fn parse(text: &str) -> io::Result<Self> {
+        let mut parser = Parser {
+            text: text,
+            position: 0,
+            depth: 0
+        };
+        let value = parser.value()?;
+
+        parser.skip_whitespace();
+
+        if parser.position != text.len() {
+            return Err(parser.error());
+        }
+
+        return Ok(value);
+    }
+
+    fn write_string(value: &str, output: &mut String) {
+        output.push('"');
+
+        for c in value.chars() {
+            match c {
+                '"' => output.push_str("\\\""),
+                '\\' => output.push_str("\\\\"),
+                '\n' => output.push_str("\\n"),
+                '\r' => output.push_str("\\r"),
+                '\t' => output.push_str("\\t"),
+                c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+                c => output.push(c)
+            }
+        }
+
+        output.push('"');
+    }
+
+    fn write(&self, output: &mut String, indent: usize) {
+        let padding = "    ".repeat(indent + 1);
+
+        match self {
+            Json::Null => output.push_str("null"),
+            Json::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+            // JSON has no representation for infinities and NaN.
+            Json::Number(value) if !value.is_finite() => output.push_str("null"),
+            Json::Number(value) => output.push_str(&value.to_string()),
+            Json::String(value) => Self::write_string(value, output),
+            Json::Array(values) if values.is_empty() => output.push_str("[]"),
+            Json::Array(values) => {
+                output.push_str("[\n");
+
+                for (i, value) in values.iter().enumerate() {
+                    output.push_str(&padding);
+                    value.write(output, indent + 1);
+                    output.push_str(if i + 1 < values.len() { ",\n" } else { "\n" });
+                }
+
+                output.push_str(&"    ".repeat(indent));
+                output.push(']');
+            },
+            Json::Object(members) if members.is_empty() => output.push_str("{}"),
+            Json::Object(members) => {
+                output.push_str("{\n");
+
+                for (i, (name, value)) in members.iter().enumerate() {
+                    output.push_str(&padding);
+                    Self::write_string(name, output);
+                    output.push_str(": ");
+                    value.write(output, indent + 1);
+                    output.push_str(if i + 1 < members.len() { ",\n" } else { "\n" });
+                }
+
+                output.push_str(&"    ".repeat(indent));
+                output.push('}');
+            }
+        }
+    }
+
+    /// Pretty-printed with four-space indentation.
+    pub(crate) fn to_pretty_string(&self) -> String {
+        let mut output = String::new();
+
+        self.write(&mut output, 0);
+
+        return output;
+    }
+}
+
+// Arrays and objects are parsed recursively, so nesting is limited to keep hostile input
+// from overflowing the stack.
+const MAX_DEPTH: usize = 128;
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    depth: usize
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> io::Error {
+        return invalid_data(&format!("invalid JSON at byte {}", self.position));
+    }
+
+    fn rest(&self) -> &'a str {
+        return &self.text[self.position..];
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+
+        self.position += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn eat(&mut self, expected: &str) -> bool {
+        self.skip_whitespace();
+
+        if self.rest().starts_with(expected) {
+            self.position += expected.len();
+            return true;
+        }
+
+        return false;
+    }
+
+    fn expect(&mut self, expected: &str) -> io::Result<()> {
+        if !self.eat(expected) {
+            return Err(self.error());
+        }
+
+        return Ok(());
+    }
+
+    fn value(&mut self) -> io::Result<Json> {
+        self.skip_whitespace();
+
+        if self.eat("null") {
+            return Ok(Json::Null);
+        }
+        if self.eat("true") {
+            return Ok(Json::Bool(true));
+        }
+        if self.eat("false") {
+            return Ok(Json::Bool(false));
+        }
+
+        return match self.rest().chars().next() {
+            Some('"') => Ok(Json::String(self.string()?)),
+            Some('[') => self.nested(Self::array),
+            Some('{') => self.nested(Self::object),
+            Some('-' | '0'..='9') => self.number(),
+            _ => Err(self.error())
+        };
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> io::Result<Json>) -> io::Result<Json> {
+        if self.depth == MAX_DEPTH {
+            return Err(invalid_data(&format!("JSON nested too deeply at byte {}", self.position)));
+        }
+
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+
+        return value;
+    }
+
+    fn number(&mut self) -> io::Result<Json> {
+        let length = self.rest().find(|c: char| !matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9')).unwrap_or(self.rest().len());
+        let value = self.rest()[..length].parse().map_err(|_| self.error())?;
+
+        self.position += length;
+
+        return Ok(Json::Number(value));
+    }
+
+    fn hex_escape(&mut self) -> io::Result<u32> {
+        let digits = self.rest().get(..4).filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit())).ok_or_else(|| self.error())?;
+        let value = u32::from_str_radix(digits, 16).map_err(|_| self.error())?;
+
+        self.position += 4;
+
+        return Ok(value);
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        let mut value = String::new();
+
+        self.expect("\"")?;
+
+        loop {
+            let c = self.rest().chars().next().ok_or_else(|| self.error())?;
+            self.position += c.len_utf8();
+
+            match c {
+                '"' => return Ok(value),
+                '\\' => {
+                    let escape = self.rest().chars().next().ok_or_else(|| self.error())?;
+                    self.position += escape.len_utf8();
+
+                    match escape {
+                        '"' | '\\' | '/' => value.push(escape),
+                        'b' => value.push('\u{8}'),
+                        'f' => value.push('\u{c}'),
+                        'n' => value.push('\n'),
+                        'r' => value.push('\r'),
+                        't' => value.push('\t'),
+                        'u' => {
+                            let mut code = self.hex_escape()?;
+
+                            // Characters outside the BMP are written as a UTF-16 surrogate pair.
+                            if (0xD800..0xDC00).contains(&code) && self.rest().starts_with("\\u") {
+                                self.position += 2;
+                                let low = self.hex_escape()?;
+
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.error());
+                                }
+
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+
+                            value.push(char::from_u32(code).ok_or_else(|| self.error())?);
+                        },
+                        _ => return Err(self.error())
+                    }
+                },
+                c => value.push(c)
+            }
+        }
+    }
+
+    fn array(&mut self) -> io::Result<Json> {
+        let mut values = Vec::new();
+
+        self.expect("[")?;
+
+        if self.eat("]") {
+            return Ok(Json::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+
+            if !self.eat(",") {
+                self.expect("]")?;
+                return Ok(Json::Array(values));
+            }
+        }
+    }
+
+    fn object(&mut self) -> io::Result<Json> {
+        let mut members = Vec::new();
+
+        self.expect("{")?;
+
+        if self.eat("}") {
+            return Ok(Json::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let name = self.string()?;
+            self.expect(":")?;
+            members.push((name, self.value()?));
+
+            if !self.eat(",") {
+                self.expect("}")?;
+                return Ok(Json::Object(members));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let value = Json::Object(vec![
+            ("null".to_owned(), Json::Null),
+            ("flags".to_owned(), Json::Array(vec![Json::Bool(true), Json::Bool(false)])),
+            ("numbers".to_owned(), Json::Array(vec![Json::Number(0.1), Json::Number(-2.5e-300), Json::Number(1e21)])),
+            ("text".to_owned(), Json::String("quote \" backslash \\ tab \t control \u{1} \u{e9}\u{1f4e1}".to_owned())),
+            ("empty".to_owned(), Json::Object(Vec::new()))
+        ]);
+
+        assert_eq!(Json::parse(&value.to_pretty_string()).unwrap(), value);
+        assert_eq!(Json::parse(r#" ["é📡", -0.5e1] "#).unwrap(), Json::Array(vec![Json::String("\u{e9}\u{1f4e1}".to_owned()), Json::Number(-5.0)]));
+        assert_eq!(Json::parse(r#""\u00e9\uD83D\uDCE1\u0041""#).unwrap(), Json::String("\u{e9}\u{1f4e1}A".to_owned()));
+    }
+
+    #[test]
+    fn nesting_depth() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+        assert!(Json::parse(&nested(MAX_DEPTH)).is_ok());
+        assert_eq!(Json::parse(&nested(MAX_DEPTH + 1)).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(Json::parse(&"[{\"a\":".repeat(100000)).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn errors() {
+        for text in ["", "[1,]", "{\"a\" 1}", "tru", "\"unterminated", "[1] 2", "{1: 2}", r#""\uD800\u0041""#, r#""\uD800""#, r#""\uDC00""#, r#""\u+abc""#, r#""\u-abc""#, r#""\u12""#] {
+            assert_eq!(Json::parse(text).unwrap_err().kind(), io::ErrorKind::InvalidData, "{text:?}");
+        }
+    }
+}
@@ -1,13 +1,44 @@
+#![allow(clippy::needless_return, clippy::redundant_field_names, clippy::should_implement_trait, clippy::from_over_into, clippy::assign_op_pattern, clippy::needless_borrow)]
+
 mod traits_ops;
 mod traits_num_traits;
-
-pub use core::fmt::{self, Debug, Display};
-pub use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign};
-
-pub use num_traits::{Bounded, AsPrimitive, FromPrimitive, NumCast, ToPrimitive, ConstOne, ConstZero, One, Zero, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, Float, FloatConst, Inv, Pow};
-
-
+mod roots;
+mod branch;
+mod gaussian;
+mod number_theory;
+mod parse;
+mod summation;
+mod polar;
+mod imaginary;
+mod format;
+mod bytes;
+mod io_util;
+mod zip;
+mod npy;
+mod json;
+mod iq;
+
+pub use roots::Roots;
+pub use branch::BranchTracker;
+pub use gaussian::GaussianInteger;
+pub use parse::{ParseComplexError, ParseComplexErrorKind};
+pub use polar::Polar;
+pub use imaginary::{Imaginary, I};
+pub use format::DisplayJ;
+pub use bytes::ByteLengthError;
+pub use npy::{NpyArray, NpyElement};
+pub use iq::{IqChunks, IqFormat, IqReader, IqWriter, SigMfCapture, SigMfMetadata};
+
+pub use core::fmt::{self, Debug, Display, LowerExp, UpperExp};
+pub use std::num::FpCategory;
+pub use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Sub, SubAssign};
+
+pub use num_traits::{Bounded, AsPrimitive, FromPrimitive, NumCast, ToPrimitive, ConstOne, ConstZero, One, Zero, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, Float, FloatConst, Inv, MulAdd, MulAddAssign, Num, NumAssign, Pow};
+
+
+/// Laid out as `[real, imaginary]`, the same as `[N; 2]` and as C99 `_Complex` / C++ `std::complex`.
 #[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Complex<N: Float> {
     pub real: N,
     pub imaginary: N
@@ -56,6 +87,15 @@ impl<N: Float> Complex<N> {
         };
     }
 
+    pub fn cis(theta: N) -> Self {
+        let (sin, cos) = theta.sin_cos();
+
+        return Self {
+            real: cos,
+            imaginary: sin
+        };
+    }
+
     pub fn min_value() -> Self {
         return Self {
             real: N::min_value(),
@@ -94,11 +134,44 @@ impl<N: Float> Complex<N> {
         *self = Self::i();
     }
 
-    pub unsafe fn from_bytes(bytes: &[u8]) -> Self {
-        // assert!(bytes.len() == std::mem::size_of::<Self>());
-        let ptr = bytes.as_ptr() as *const Self;
+    pub fn to_array(&self) -> [N; 2] {
+        return [self.real, self.imaginary];
+    }
+
+    pub fn as_array(&self) -> &[N; 2] {
+        // Sound because of #[repr(C)]: two fields of the same type have no padding.
+        return unsafe { &*(self as *const Self as *const [N; 2]) };
+    }
+
+    pub fn as_array_mut(&mut self) -> &mut [N; 2] {
+        return unsafe { &mut *(self as *mut Self as *mut [N; 2]) };
+    }
 
-        return unsafe { ptr.read_unaligned() };
+    /// Views a slice of complex numbers as interleaved `[re, im, re, im, ...]` parts.
+    pub fn as_interleaved(values: &[Self]) -> &[N] {
+        return unsafe { std::slice::from_raw_parts(values.as_ptr() as *const N, values.len() * 2) };
+    }
+
+    pub fn as_interleaved_mut(values: &mut [Self]) -> &mut [N] {
+        return unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut N, values.len() * 2) };
+    }
+
+    /// Views interleaved `[re, im, re, im, ...]` parts as complex numbers, or returns None if
+    /// the length is odd.
+    pub fn from_interleaved(values: &[N]) -> Option<&[Self]> {
+        if !values.len().is_multiple_of(2) {
+            return None;
+        }
+
+        return Some(unsafe { std::slice::from_raw_parts(values.as_ptr() as *const Self, values.len() / 2) });
+    }
+
+    pub fn from_interleaved_mut(values: &mut [N]) -> Option<&mut [Self]> {
+        if !values.len().is_multiple_of(2) {
+            return None;
+        }
+
+        return Some(unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len() / 2) });
     }
 
     pub fn as_bytes(&self) -> &[u8] {
@@ -109,12 +182,28 @@ impl<N: Float> Complex<N> {
         return unsafe { std::slice::from_raw_parts_mut((self as *mut Self) as *mut u8, std::mem::size_of::<Self>()) };
     }
 
-    pub unsafe fn to_bytes<const LEN: usize>(&self) -> [u8; LEN] {
-        // assert_eq!(LEN, std::mem::size_of::<Self>());
-        let mut arr: [u8; LEN] = unsafe { std::mem::MaybeUninit::uninit().assume_init() };
-        unsafe { std::ptr::copy_nonoverlapping(self as *const Self as *const u8, arr.as_mut_ptr(), LEN) };
+    /// Converts both parts to another float type, or returns None if a finite part
+    /// is out of the range of `M`.
+    pub fn cast<M: Float>(&self) -> Option<Complex<M>> {
+        let value = Complex {
+            real: M::from(self.real)?,
+            imaginary: M::from(self.imaginary)?
+        };
+
+        if self.is_finite() && !value.is_finite() {
+            return None;
+        }
+
+        return Some(value);
+    }
 
-        return arr;
+    /// Converts both parts to another float type with `as` semantics: out-of-range parts
+    /// become infinite. Not named `as_`, which `AsPrimitive` uses to convert the real part.
+    pub fn as_complex<M: Float+'static>(self) -> Complex<M> where N: AsPrimitive<M> {
+        return Complex {
+            real: self.real.as_(),
+            imaginary: self.imaginary.as_()
+        };
     }
 
     pub fn extract_real(&self) -> Self {
@@ -132,7 +221,11 @@ impl<N: Float> Complex<N> {
     }
 
     pub fn amplitude(&self) -> N {
-        return (self.real * self.real + self.imaginary * self.imaginary).sqrt();
+        return self.real.hypot(self.imaginary);
+    }
+
+    pub fn norm(&self) -> N {
+        return Self::amplitude(self);
     }
 
     pub fn argument(&self) -> N {
@@ -190,10 +283,74 @@ impl<N: Float> Complex<N> {
         *self = Self::subf(self, other);
     }
 
+    // Maps an infinite component to +-1 and a finite one to +-0, keeping the sign.
+    fn box_infinity(value: N) -> N {
+        return if value.is_infinite() { N::one() } else { N::zero() }.copysign(value);
+    }
+
+    fn nan_to_zero(value: N) -> N {
+        return if value.is_nan() { N::zero().copysign(value) } else { value };
+    }
+
+    // C99 Annex G recovery for a product (a + ib)(c + id) whose naive evaluation gave NaN + iNaN.
+    fn recover_product(mut a: N, mut b: N, mut c: N, mut d: N) -> Self {
+        let partial_overflow = (a * c).is_infinite() || (b * d).is_infinite() || (a * d).is_infinite() || (b * c).is_infinite();
+        let mut recalculate = false;
+
+        if a.is_infinite() || b.is_infinite() {
+            a = Self::box_infinity(a);
+            b = Self::box_infinity(b);
+            c = Self::nan_to_zero(c);
+            d = Self::nan_to_zero(d);
+            recalculate = true;
+        }
+        if c.is_infinite() || d.is_infinite() {
+            c = Self::box_infinity(c);
+            d = Self::box_infinity(d);
+            a = Self::nan_to_zero(a);
+            b = Self::nan_to_zero(b);
+            recalculate = true;
+        }
+        if !recalculate && partial_overflow {
+            a = Self::nan_to_zero(a);
+            b = Self::nan_to_zero(b);
+            c = Self::nan_to_zero(c);
+            d = Self::nan_to_zero(d);
+            recalculate = true;
+        }
+
+        if !recalculate {
+            return Self::new(N::nan(), N::nan());
+        }
+
+        return Self {
+            real: N::infinity() * (a * c - b * d),
+            imaginary: N::infinity() * (a * d + b * c)
+        };
+    }
+
     pub fn mul(&self, other: &Self) -> Self {
+        let real = self.real * other.real - self.imaginary * other.imaginary;
+        let imaginary = self.real * other.imaginary + self.imaginary * other.real;
+
+        if real.is_nan() || imaginary.is_nan() {
+            // An infinity times a real number must not pick up a NaN from 0 * inf.
+            if !self.has_nan() && !other.has_nan() {
+                if other.imaginary.is_zero() && !Self::mulf(self, other.real).has_nan() {
+                    return Self::mulf(self, other.real);
+                }
+                if self.imaginary.is_zero() && !Self::mulf(other, self.real).has_nan() {
+                    return Self::mulf(other, self.real);
+                }
+            }
+            if real.is_nan() && imaginary.is_nan() {
+                return Self::recover_product(self.real, self.imaginary, other.real, other.imaginary);
+            }
+        }
+
         return Self {
-            real: self.real * other.real - self.imaginary * other.imaginary,
-            imaginary: self.real * other.imaginary + self.imaginary * other.real
+            real: real,
+            imaginary: imaginary
         };
     }
 
@@ -201,6 +358,47 @@ impl<N: Float> Complex<N> {
         *self = Self::mul(self, other);
     }
 
+    // a * b - c * d evaluated with Kahan's fused multiply-add trick, so that
+    // cancellation between the two products does not destroy the result.
+    fn fused_difference(a: N, b: N, c: N, d: N) -> N {
+        let cd = c * d;
+        let error = (-c).mul_add(d, cd);
+
+        return a.mul_add(b, -cd) + error;
+    }
+
+    /// Multiplication whose real and imaginary parts each have a small relative error,
+    /// at the cost of four fused multiply-adds. Falls back to `mul` for non-finite results.
+    pub fn mul_accurate(&self, other: &Self) -> Self {
+        let (a, b, c, d) = (self.real, self.imaginary, other.real, other.imaginary);
+        let product = Self {
+            real: Self::fused_difference(a, c, b, d),
+            imaginary: Self::fused_difference(a, d, -b, c)
+        };
+
+        if product.has_nan() {
+            return Self::mul(self, other);
+        }
+
+        return product;
+    }
+
+    pub fn mul_accurate_assign(&mut self, other: &Self) {
+        *self = Self::mul_accurate(self, other);
+    }
+
+    /// Computes `self * a + b` with fused multiply-adds.
+    pub fn mul_add(&self, a: &Self, b: &Self) -> Self {
+        return Self {
+            real: self.real.mul_add(a.real, (-self.imaginary).mul_add(a.imaginary, b.real)),
+            imaginary: self.real.mul_add(a.imaginary, self.imaginary.mul_add(a.real, b.imaginary))
+        };
+    }
+
+    pub fn mul_add_assign(&mut self, a: &Self, b: &Self) {
+        *self = Self::mul_add(self, a, b);
+    }
+
     pub fn mulf(&self, other: N) -> Self {
         return Self {
             real: self.real * other,
@@ -212,14 +410,99 @@ impl<N: Float> Complex<N> {
         *self = Self::mulf(self, other);
     }
 
+    // Smith's division of (a + ib) / (c + id) for |d| <= |c|, with the
+    // underflow guards of Baudin and Smith's robust variant.
+    fn smith_division(a: N, b: N, c: N, d: N) -> (N, N) {
+        let r = d / c;
+        let t = (c + d * r).recip();
+
+        if r.is_zero() {
+            return ((a + d * (b / c)) * t, (b - d * (a / c)) * t);
+        }
+
+        let br = b * r;
+        let ar = a * r;
+        let e = if br.is_zero() { a * t + (b * t) * r } else { (a + br) * t };
+        let f = if ar.is_zero() { b * t - (a * t) * r } else { (b - ar) * t };
+
+        return (e, f);
+    }
+
     pub fn div(&self, other: &Self) -> Self {
-        let denominator = other.real * other.real + other.imaginary * other.imaginary;
+        let (mut a, mut b, mut c, mut d) = (self.real, self.imaginary, other.real, other.imaginary);
+        let two = N::one() + N::one();
+        let overflow = N::max_value() / two;
+        let underflow = N::min_positive_value() * two / N::epsilon();
+        let boost = two / (N::epsilon() * N::epsilon());
+        let mut scale = N::one();
+
+        let ab = a.abs().max(b.abs());
+        let cd = c.abs().max(d.abs());
+
+        if ab >= overflow {
+            a = a / two;
+            b = b / two;
+            scale = scale * two;
+        }
+        if cd >= overflow {
+            c = c / two;
+            d = d / two;
+            scale = scale / two;
+        }
+        if ab <= underflow {
+            a = a * boost;
+            b = b * boost;
+            scale = scale / boost;
+        }
+        if cd <= underflow {
+            c = c * boost;
+            d = d * boost;
+            scale = scale * boost;
+        }
+
+        let (e, f) = if d.abs() <= c.abs() {
+            Self::smith_division(a, b, c, d)
+        } else {
+            let (e, f) = Self::smith_division(b, a, d, c);
+            (e, -f)
+        };
+
+        if (e.is_nan() || f.is_nan()) && other.imaginary.is_zero() && !other.real.is_zero() && !self.has_nan() && !other.has_nan() {
+            return Self::divf(self, other.real);
+        }
+        if e.is_nan() && f.is_nan() {
+            return Self::recover_quotient(self, other);
+        }
+
         return Self {
-            real: (self.real * other.real + self.imaginary * other.imaginary) / denominator,
-            imaginary: (self.imaginary * other.real - self.real * other.imaginary) / denominator
+            real: e * scale,
+            imaginary: f * scale
         };
     }
 
+    // C99 Annex G recovery for a quotient whose evaluation gave NaN + iNaN.
+    fn recover_quotient(numerator: &Self, denominator: &Self) -> Self {
+        let (a, b, c, d) = (numerator.real, numerator.imaginary, denominator.real, denominator.imaginary);
+
+        if denominator.is_zero() && !(a.is_nan() && b.is_nan()) {
+            let infinity = N::infinity().copysign(c);
+
+            return Self::new(infinity * a, infinity * b);
+        }
+        if numerator.is_infinite() && denominator.is_finite() {
+            let (a, b) = (Self::box_infinity(a), Self::box_infinity(b));
+
+            return Self::new(N::infinity() * (a * c + b * d), N::infinity() * (b * c - a * d));
+        }
+        if denominator.is_infinite() && numerator.is_finite() {
+            let (c, d) = (Self::box_infinity(c), Self::box_infinity(d));
+
+            return Self::new(N::zero() * (a * c + b * d), N::zero() * (b * c - a * d));
+        }
+
+        return Self::new(N::nan(), N::nan());
+    }
+
     pub fn div_assign(&mut self, other: &Self) {
         *self = Self::div(self, other);
     }
@@ -235,6 +518,24 @@ impl<N: Float> Complex<N> {
         *self = Self::divf(self, other);
     }
 
+    /// Remainder on the Gaussian lattice spanned by `other`: `self - other * round(self / other)`,
+    /// rounding each part of the quotient to the nearest integer.
+    pub fn rem(&self, other: &Self) -> Self {
+        return Self::sub(self, &Self::mul(other, &Self::div(self, other).naive_round()));
+    }
+
+    pub fn rem_assign(&mut self, other: &Self) {
+        *self = Self::rem(self, other);
+    }
+
+    pub fn remf(&self, other: N) -> Self {
+        return Self::sub(self, &Self::divf(self, other).naive_round().mulf(other));
+    }
+
+    pub fn remf_assign(&mut self, other: N) {
+        *self = Self::remf(self, other);
+    }
+
     pub fn neg(&self) -> Self {
         return Self {
             real: -self.real,
@@ -243,12 +544,7 @@ impl<N: Float> Complex<N> {
     }
 
     pub fn recip(&self) -> Self {
-        let divisor = self.real * self.real + self.imaginary * self.imaginary;
-
-        return Self {
-            real: self.real / divisor,
-            imaginary: -self.imaginary / divisor
-        };
+        return Self::div(&Self::one(), self);
     }
 
     pub fn inv(&self) -> Self {
@@ -298,6 +594,17 @@ impl<N: Float> Complex<N> {
     }
 
     pub fn exp(&self) -> Self {
+        if self.imaginary.is_zero() {
+            return Self::new(self.real.exp(), self.imaginary);
+        }
+        if self.real.is_infinite() && !self.imaginary.is_finite() {
+            return if self.real.is_sign_positive() {
+                Self::new(self.real, N::nan())
+            } else {
+                Self::zero()
+            };
+        }
+
         let exp_real = self.real.exp();
         return Self {
             real: exp_real * self.imaginary.cos(),
@@ -313,31 +620,213 @@ impl<N: Float> Complex<N> {
     }
 
     pub fn log(&self, base: N) -> Self {
+        if base.is_sign_positive() {
+            return Self::divf(&self.ln(), base.ln());
+        }
+
         return Self::div(&self.ln(), &Self::from_real(base).ln());
     }
 
-    pub fn sin(&self) -> Self {
-        let a = self.imaginary.exp();
-        let b = self.imaginary.neg().exp();
+    pub fn exp_m1(&self) -> Self {
+        let (x, y) = (self.real, self.imaginary);
+
+        if y.is_zero() {
+            return Self::new(x.exp_m1(), y);
+        }
+
+        // Re(exp(z) - 1) = expm1(x) cos(y) + (cos(y) - 1) with cos(y) - 1 = -2 sin^2(y / 2)
         let two = N::one() + N::one();
+        let half_sin = (y / two).sin();
 
         return Self {
-            real: self.real.sin() * (b + a) / two,
-            imaginary: self.real.cos() * (b - a) / two
+            real: x.exp_m1() * y.cos() - two * half_sin * half_sin,
+            imaginary: x.exp() * y.sin()
+        };
+    }
+
+    pub fn ln_1p(&self) -> Self {
+        let (x, y) = (self.real, self.imaginary);
+        let one = N::one();
+        let two = one + one;
+        let half = one / two;
+
+        if x.abs() >= half || y.abs() >= half {
+            return Self::addf(self, one).ln();
+        }
+        if y.is_zero() {
+            return Self::new(x.ln_1p(), y);
+        }
+
+        // ln|1 + z| = ln(1 + (x(2 + x) + y^2)) / 2
+        return Self {
+            real: (x * (two + x) + y * y).ln_1p() * half,
+            imaginary: y.atan2(one + x)
+        };
+    }
+
+    pub fn sin(&self) -> Self {
+        // sin(z) = -i sinh(iz) = sin(x) cosh(y) + i cos(x) sinh(y)
+        let sinh = Self::new(-self.imaginary, self.real).sinh();
+
+        return Self {
+            real: sinh.imaginary,
+            imaginary: -sinh.real
         };
     }
 
     pub fn cos(&self) -> Self {
-        let a = self.imaginary.exp();
-        let b = self.imaginary.neg().exp();
-        let two = N::one() + N::one();
+        // cos(z) = cosh(iz) = cos(x) cosh(y) - i sin(x) sinh(y)
+        return Self::new(-self.imaginary, self.real).cosh();
+    }
+
+    // Beyond this magnitude, exp(-2|t|) is below the precision of N and the
+    // asymptotic forms of tan/tanh/sec/csc are exact to working precision.
+    fn large_argument_threshold() -> N {
+        return -N::epsilon().ln();
+    }
+
+    pub fn tan(&self) -> Self {
+        // tan(z) = -i tanh(iz)
+        let tanh = Self::new(-self.imaginary, self.real).tanh();
+
+        return Self {
+            real: tanh.imaginary,
+            imaginary: -tanh.real
+        };
+    }
+
+    pub fn tan_assign(&mut self) {
+        *self = Self::tan(self);
+    }
+
+    pub fn cot(&self) -> Self {
+        return Self::tan(self).recip();
+    }
+
+    pub fn cot_assign(&mut self) {
+        *self = Self::cot(self);
+    }
+
+    pub fn sec(&self) -> Self {
+        let (x, y) = (self.real, self.imaginary);
+
+        if y.abs() > Self::large_argument_threshold() {
+            let two = N::one() + N::one();
+            let scale = two * (-y.abs()).exp();
+
+            return Self {
+                real: scale * x.cos(),
+                imaginary: scale * x.sin() * N::one().copysign(y)
+            };
+        }
+
+        // 1 / cos(z) = conj(cos(z)) / |cos(z)|^2 with |cos(z)|^2 = cos^2(x) + sinh^2(y)
+        let (sin_x, cos_x) = x.sin_cos();
+        let sinh_y = y.sinh();
+        let denominator = cos_x * cos_x + sinh_y * sinh_y;
+
+        return Self {
+            real: cos_x * y.cosh() / denominator,
+            imaginary: sin_x * sinh_y / denominator
+        };
+    }
+
+    pub fn sec_assign(&mut self) {
+        *self = Self::sec(self);
+    }
+
+    pub fn csc(&self) -> Self {
+        let (x, y) = (self.real, self.imaginary);
+
+        if y.abs() > Self::large_argument_threshold() {
+            let two = N::one() + N::one();
+            let scale = two * (-y.abs()).exp();
+
+            return Self {
+                real: scale * x.sin(),
+                imaginary: -scale * x.cos() * N::one().copysign(y)
+            };
+        }
+
+        // 1 / sin(z) = conj(sin(z)) / |sin(z)|^2 with |sin(z)|^2 = sin^2(x) + sinh^2(y)
+        let (sin_x, cos_x) = x.sin_cos();
+        let sinh_y = y.sinh();
+        let denominator = sin_x * sin_x + sinh_y * sinh_y;
+
+        return Self {
+            real: sin_x * y.cosh() / denominator,
+            imaginary: -cos_x * sinh_y / denominator
+        };
+    }
+
+    pub fn csc_assign(&mut self) {
+        *self = Self::csc(self);
+    }
+
+    pub fn sinh(&self) -> Self {
+        let (x, y) = (self.real, self.imaginary);
+
+        if y.is_zero() {
+            return Self::new(x.sinh(), y);
+        }
 
         return Self {
-            real: self.real.cos() * (b + a) / two,
-            imaginary: self.real.sin() * (b - a) / two
+            real: x.sinh() * y.cos(),
+            imaginary: x.cosh() * y.sin()
         };
     }
 
+    pub fn sinh_assign(&mut self) {
+        *self = Self::sinh(self);
+    }
+
+    pub fn cosh(&self) -> Self {
+        let (x, y) = (self.real, self.imaginary);
+
+        if y.is_zero() {
+            return Self::new(x.cosh(), x.signum() * y);
+        }
+
+        return Self {
+            real: x.cosh() * y.cos(),
+            imaginary: x.sinh() * y.sin()
+        };
+    }
+
+    pub fn cosh_assign(&mut self) {
+        *self = Self::cosh(self);
+    }
+
+    pub fn tanh(&self) -> Self {
+        let (x, y) = (self.real, self.imaginary);
+
+        if x.abs() > Self::large_argument_threshold() {
+            let four = N::one() + N::one() + N::one() + N::one();
+
+            return Self {
+                real: N::one().copysign(x),
+                imaginary: four * y.sin() * y.cos() * (-(x.abs() + x.abs())).exp()
+            };
+        }
+
+        // Kahan's formulation: tanh(x + iy) = ((1 + t^2) s c + it) / (1 + (1 + t^2) s^2)
+        // with t = tan(y), s = sinh(x), c = cosh(x) = sqrt(1 + s^2).
+        let t = y.tan();
+        let beta = N::one() + t * t;
+        let s = x.sinh();
+        let rho = (N::one() + s * s).sqrt();
+        let denominator = N::one() + beta * s * s;
+
+        return Self {
+            real: beta * rho * s / denominator,
+            imaginary: t / denominator
+        };
+    }
+
+    pub fn tanh_assign(&mut self) {
+        *self = Self::tanh(self);
+    }
+
     pub fn copysign(&self, sign: &Self) -> Self {
         return Self {
             real: self.real.copysign(sign.real),
@@ -345,37 +834,153 @@ impl<N: Float> Complex<N> {
         };
     }
 
-    pub fn powi(&self,  exponent: isize) -> Self {
+    pub fn powu(&self, exponent: usize) -> Self {
         let mut n = Self::one();
-        let (mut base, mut exponent) = if exponent > 0 {
-            (*self, exponent)
-        } else {
-            (self.recip(), -exponent)
-        };
+        let mut base = *self;
+        let mut exponent = exponent;
 
         while exponent > 0 {
             if exponent % 2 == 1 {
-                n = n * base;
+                n *= base;
             }
-            base *= base;
 
             exponent /= 2;
+
+            if exponent > 0 {
+                base *= base;
+            }
         }
 
         return n;
     }
 
+    /// `0^n` for `n < 0` is the real infinity returned by `powf`, as is any power that
+    /// underflows to zero before being inverted.
+    pub fn powi(&self, exponent: isize) -> Self {
+        let n = Self::powu(self, exponent.unsigned_abs());
+
+        if exponent < 0 {
+            // The reciprocal of zero would be inf + NaN i.
+            if n.is_zero() {
+                return Self::new(N::infinity(), N::zero());
+            }
+
+            return n.recip();
+        }
+
+        return n;
+    }
+
+    /// Small integral exponents (|x| <= 64) of finite bases go through `powi` and are exact
+    /// whenever the repeated products are, e.g. `(1 + i)^2 = 2i`. `0^x` is `0` for `x > 0`
+    /// and an infinity for `x < 0`.
     pub fn powf(&self, exponent: N) -> Self {
-        return (self.ln() * Self::from_real(exponent)).exp();
+        if exponent.is_zero() {
+            return Self::one();
+        }
+        if self.is_zero() {
+            if exponent > N::zero() {
+                return Self::zero();
+            }
+            if exponent < N::zero() {
+                return Self::new(N::infinity(), N::zero());
+            }
+
+            return Self::new(N::nan(), N::nan());
+        }
+        // Repeated squaring compounds rounding error with the number of steps, so large
+        // exponents are more accurate through ln/exp.
+        if self.is_finite() && exponent.trunc() == exponent && let Some(exponent) = exponent.to_isize() && exponent.unsigned_abs() <= 64 {
+            return Self::powi(self, exponent);
+        }
+
+        return Self::mulf(&self.ln(), exponent).exp();
     }
 
+    /// Real exponents go through `powf`. `0^z` is `0` for `Re z > 0`, a complex
+    /// infinity for `Re z < 0`, and NaN otherwise.
     pub fn pow(&self, other: &Self) -> Self {
+        if other.imaginary.is_zero() {
+            return Self::powf(self, other.real);
+        }
+        if self.is_zero() {
+            if other.real > N::zero() {
+                return Self::zero();
+            }
+            if other.real < N::zero() {
+                return Self::new(N::infinity(), N::nan());
+            }
+
+            return Self::new(N::nan(), N::nan());
+        }
+
         let r = self.amplitude();
         let arg = self.argument();
 
         return Complex::new(-other.imaginary * arg, other.imaginary * r.ln() + other.real * arg).exp().mulf(r.powf(other.real));
     }
 
+    pub fn sqrt(&self) -> Self {
+        let (x, y) = (self.real, self.imaginary);
+        let two = N::one() + N::one();
+        let four = two + two;
+
+        if x.is_zero() && y.is_zero() {
+            return Self::new(N::zero(), y);
+        }
+        if y.is_infinite() {
+            return Self::new(N::infinity(), y);
+        }
+        if x.is_infinite() {
+            let finite_part = if y.is_nan() { y } else { N::zero() };
+
+            return if x.is_sign_positive() {
+                Self::new(x, finite_part.copysign(y))
+            } else {
+                Self::new(finite_part, N::infinity().copysign(y))
+            };
+        }
+
+        // Rescale by even powers of two so that |x| + |z| neither overflows nor loses bits to underflow.
+        if x.abs() >= N::max_value() / four || y.abs() >= N::max_value() / four {
+            return Self::new(x / four, y / four).sqrt().mulf(two);
+        }
+        if x.abs() < N::min_positive_value() * four && y.abs() < N::min_positive_value() * four {
+            let scale = (N::epsilon() * N::epsilon()).recip();
+
+            return Self::new(x * scale, y * scale).sqrt().mulf(N::epsilon());
+        }
+
+        let t = ((x.abs() + x.hypot(y)) / two).sqrt();
+
+        if x.is_sign_positive() {
+            return Self::new(t, y / (two * t));
+        } else {
+            return Self::new(y.abs() / (two * t), t.copysign(y));
+        }
+    }
+
+    pub fn sqrt_assign(&mut self) {
+        *self = Self::sqrt(self);
+    }
+
+    /// Principal cube root, with the branch cut on the negative real axis. Negative reals
+    /// therefore give the root in the upper half plane, e.g. `cbrt(-8) = 1 + 1.732i`, not `-2`;
+    /// use `N::cbrt` on the real part for the real root.
+    pub fn cbrt(&self) -> Self {
+        if self.imaginary.is_zero() && self.real.is_sign_positive() {
+            return Self::new(self.real.cbrt(), self.imaginary);
+        }
+
+        let three = N::one() + N::one() + N::one();
+
+        return Self::from_argument_amplitude(self.argument() / three, self.amplitude().cbrt());
+    }
+
+    pub fn cbrt_assign(&mut self) {
+        *self = Self::cbrt(self);
+    }
+
     pub fn bitand(&self, other: &Self) -> Self {
         unsafe {
             let mut result: Self = std::mem::zeroed();
@@ -411,7 +1016,7 @@ impl<N: Float> Complex<N> {
     }
 
     pub fn bitor_assign(&mut self, other: &Self) {
-        *self = Self::bitand(self, other);
+        *self = Self::bitor(self, other);
     }
 
     pub fn bitxor(&self, other: &Self) -> Self {
@@ -455,16 +1060,48 @@ impl<N: Float> Complex<N> {
         return self.real.is_zero();
     }
 
-    pub fn is_finite(&self) -> (bool, bool) {
-        return (self.real.is_finite(), self.imaginary.is_finite());
+    fn has_nan(&self) -> bool {
+        return self.real.is_nan() || self.imaginary.is_nan();
+    }
+
+    pub fn is_finite(&self) -> bool {
+        return self.real.is_finite() && self.imaginary.is_finite();
+    }
+
+    /// A value is infinite when either part is infinite, even if the other part is NaN.
+    pub fn is_infinite(&self) -> bool {
+        return self.real.is_infinite() || self.imaginary.is_infinite();
+    }
+
+    pub fn is_nan(&self) -> bool {
+        return !self.is_infinite() && (self.real.is_nan() || self.imaginary.is_nan());
     }
 
-    pub fn is_normal(&self) -> (bool, bool) {
-        return (self.real.is_normal(), self.imaginary.is_normal());
+    pub fn is_normal(&self) -> bool {
+        return self.classify() == FpCategory::Normal;
     }
 
-    pub fn is_subnormal(&self) -> (bool, bool) {
-        return (self.real.is_subnormal(), self.imaginary.is_subnormal());
+    pub fn is_subnormal(&self) -> bool {
+        return self.classify() == FpCategory::Subnormal;
+    }
+
+    /// Infinite and NaN take precedence over the other categories. A finite non-zero
+    /// value is subnormal when either part is subnormal, and normal otherwise.
+    pub fn classify(&self) -> FpCategory {
+        if self.is_infinite() {
+            return FpCategory::Infinite;
+        }
+        if self.is_nan() {
+            return FpCategory::Nan;
+        }
+        if self.is_zero() {
+            return FpCategory::Zero;
+        }
+        if self.real.is_subnormal() || self.imaginary.is_subnormal() {
+            return FpCategory::Subnormal;
+        }
+
+        return FpCategory::Normal;
     }
 
     pub fn is_sign_positive(&self) -> (bool, bool) {
@@ -476,6 +1113,320 @@ impl<N: Float> Complex<N> {
     }
 }
 
+impl<N: Float+FloatConst> Complex<N> {
+    // ln(2) - LN_2, the part of ln(2) below the precision of N.
+    fn ln_2_tail() -> N {
+        let tail = std::f64::consts::LN_2 - N::LN_2().to_f64().unwrap();
+
+        return N::from(tail + 2.3190468138462996e-17).unwrap();
+    }
+
+    pub fn exp2(&self) -> Self {
+        if self.imaginary.is_zero() {
+            return Self::new(self.real.exp2(), self.imaginary);
+        }
+        if !self.is_finite() {
+            return Self::mulf(self, N::LN_2()).exp();
+        }
+
+        // The angle y ln(2) is carried as theta + error, since rounding the product alone
+        // costs about |y| ulps in sin and cos. The error then enters to first order.
+        let y = self.imaginary;
+        let theta = y * N::LN_2();
+        let error = y.mul_add(N::LN_2(), -theta) + y * Self::ln_2_tail();
+        let (sin, cos) = theta.sin_cos();
+        let amplitude = self.real.exp2();
+
+        return Self {
+            real: amplitude * (cos - sin * error),
+            imaginary: amplitude * (sin + cos * error)
+        };
+    }
+
+    pub fn log2(&self) -> Self {
+        return Self {
+            real: self.amplitude().log2(),
+            imaginary: self.argument() * N::LOG2_E()
+        };
+    }
+
+    pub fn log10(&self) -> Self {
+        return Self {
+            real: self.amplitude().log10(),
+            imaginary: self.argument() * N::LOG10_E()
+        };
+    }
+
+    /// Logarithm on sheet `k` of the Riemann surface, `ln(z) + 2 pi i k`.
+    /// Sheet 0 is the principal branch returned by `ln`.
+    pub fn ln_branch(&self, k: isize) -> Self {
+        let ln = self.ln();
+
+        return Self {
+            real: ln.real,
+            imaginary: ln.imaginary + N::TAU() * N::from(k).unwrap()
+        };
+    }
+
+    pub fn powf_branch(&self, exponent: N, k: isize) -> Self {
+        return Self::mulf(&self.ln_branch(k), exponent).exp();
+    }
+
+    /// Iterates over the `n` n-th roots of `self` in counter-clockwise order,
+    /// starting with the principal root.
+    pub fn roots(&self, n: usize) -> Roots<N> {
+        return Roots::new(self, n);
+    }
+
+    // Hull, Fairgrieve and Tang's algorithm for x, y >= 0. Returns the real parts
+    // of asin(x + iy) and acos(x + iy) and the magnitude of their imaginary parts.
+    fn inverse_sine_parts(x: N, y: N) -> (N, N, N) {
+        let one = N::one();
+        let half = one / (one + one);
+
+        if x > N::epsilon().recip() || y > N::epsilon().recip() {
+            let (big, small) = if x > y { (x, y) } else { (y, x) };
+            let imaginary = if big.is_infinite() {
+                big
+            } else {
+                big.ln() + ((small / big) * (small / big)).ln_1p() * half + N::LN_2()
+            };
+
+            return (x.atan2(y), y.atan2(x), imaginary);
+        }
+
+        let r = (x + one).hypot(y);
+        let s = (x - one).hypot(y);
+        let a = (r + s) * half;
+        let b = x / a;
+        let y2 = y * y;
+
+        let (asin_real, acos_real) = if b <= N::from(0.6417).unwrap() {
+            (b.asin(), b.acos())
+        } else {
+            let d = if x <= one {
+                ((a + x) * half * (y2 / (r + x + one) + (s + (one - x)))).sqrt()
+            } else {
+                y * ((a + x) * half * ((r + x + one).recip() + (s + (x - one)).recip())).sqrt()
+            };
+
+            (x.atan2(d), d.atan2(x))
+        };
+
+        let imaginary = if x < one && y < N::epsilon() * (one - x) {
+            y / ((one - x) * (one + x)).sqrt()
+        } else if a <= one + half {
+            let am1 = if x < one {
+                half * (y2 / (r + x + one) + y2 / (s + (one - x)))
+            } else {
+                half * (y2 / (r + x + one) + (s + (x - one)))
+            };
+
+            (am1 + (am1 * (a + one)).sqrt()).ln_1p()
+        } else {
+            (a + ((a - one) * (a + one)).sqrt()).ln()
+        };
+
+        return (asin_real, acos_real, imaginary);
+    }
+
+    /// Principal inverse sine, with branch cuts on the real axis outside [-1, 1].
+    /// The sign of the imaginary part follows the sign of `imaginary` on the cuts.
+    pub fn asin(&self) -> Self {
+        let (asin_real, _, imaginary) = Self::inverse_sine_parts(self.real.abs(), self.imaginary.abs());
+
+        return Self {
+            real: asin_real.copysign(self.real),
+            imaginary: imaginary.copysign(self.imaginary)
+        };
+    }
+
+    pub fn asin_assign(&mut self) {
+        *self = Self::asin(self);
+    }
+
+    /// Principal inverse cosine, with branch cuts on the real axis outside [-1, 1].
+    /// The real part lies in [0, pi] and the sign of the imaginary part is opposite
+    /// to the sign of `imaginary`, including on the cuts.
+    pub fn acos(&self) -> Self {
+        let (_, acos_real, imaginary) = Self::inverse_sine_parts(self.real.abs(), self.imaginary.abs());
+        let real = if self.real.is_sign_negative() { N::PI() - acos_real } else { acos_real };
+
+        return Self {
+            real: real,
+            imaginary: imaginary.copysign(-self.imaginary)
+        };
+    }
+
+    pub fn acos_assign(&mut self) {
+        *self = Self::acos(self);
+    }
+
+    /// Principal inverse tangent, with branch cuts on the imaginary axis outside [-i, i].
+    /// The sign of the real part follows the sign of `real` on the cuts.
+    pub fn atan(&self) -> Self {
+        // atan(z) = -i atanh(iz)
+        let atanh = Self::new(-self.imaginary, self.real).atanh();
+
+        return Self {
+            real: atanh.imaginary,
+            imaginary: -atanh.real
+        };
+    }
+
+    pub fn atan_assign(&mut self) {
+        *self = Self::atan(self);
+    }
+
+    /// Two-argument inverse tangent of `self / other`, defined as
+    /// `-i ln((other + i self) / sqrt(other^2 + self^2))`. Equals `N::atan2` when both
+    /// arguments are real.
+    pub fn atan2(&self, other: &Self) -> Self {
+        if self.is_pure_real() && other.is_pure_real() {
+            return Self::from_real(self.real.atan2(other.real));
+        }
+
+        let two = N::one() + N::one();
+        let numerator = Self::new(other.real - self.imaginary, other.imaginary + self.real).ln();
+        let denominator = Self::add(&Self::mul(other, other), &Self::mul(self, self)).ln().divf(two);
+        let log = Self::sub(&numerator, &denominator);
+
+        return Self {
+            real: log.imaginary,
+            imaginary: -log.real
+        };
+    }
+
+    pub fn atan2_assign(&mut self, other: &Self) {
+        *self = Self::atan2(self, other);
+    }
+
+    /// Principal inverse hyperbolic sine, with branch cuts on the imaginary axis
+    /// outside [-i, i]. The sign of the real part follows the sign of `real` on the cuts.
+    pub fn asinh(&self) -> Self {
+        let (asin_real, _, imaginary) = Self::inverse_sine_parts(self.imaginary.abs(), self.real.abs());
+
+        return Self {
+            real: imaginary.copysign(self.real),
+            imaginary: asin_real.copysign(self.imaginary)
+        };
+    }
+
+    pub fn asinh_assign(&mut self) {
+        *self = Self::asinh(self);
+    }
+
+    /// Principal inverse hyperbolic cosine, with a branch cut on the real axis below 1.
+    /// The real part is non-negative and the sign of the imaginary part follows the sign
+    /// of `imaginary` on the cut.
+    pub fn acosh(&self) -> Self {
+        let (_, acos_real, real) = Self::inverse_sine_parts(self.real.abs(), self.imaginary.abs());
+        let imaginary = if self.real.is_sign_negative() { N::PI() - acos_real } else { acos_real };
+
+        return Self {
+            real: real,
+            imaginary: imaginary.copysign(self.imaginary)
+        };
+    }
+
+    pub fn acosh_assign(&mut self) {
+        *self = Self::acosh(self);
+    }
+
+    /// Principal inverse hyperbolic tangent, with branch cuts on the real axis outside
+    /// [-1, 1]. The sign of the imaginary part follows the sign of `imaginary` on the cuts.
+    pub fn atanh(&self) -> Self {
+        let (x, y) = (self.real.abs(), self.imaginary.abs());
+        let one = N::one();
+        let two = one + one;
+        let four = two + two;
+
+        let (real, imaginary) = if x > N::max_value().sqrt() / four || y > N::max_value().sqrt() / four {
+            // atanh(z) = 1/z + i pi/2 to working precision
+            let real = if x.is_infinite() || y.is_infinite() {
+                N::zero()
+            } else {
+                let h = x.hypot(y);
+                (x / h) / h
+            };
+
+            (real, N::FRAC_PI_2())
+        } else {
+            let one_minus_x = one - x;
+            let denominator = one_minus_x * one_minus_x + y * y;
+
+            // Close to the singularity at 1 the quotient overflows, but there the
+            // direct difference of logarithms does not cancel.
+            let real = if denominator < N::epsilon() {
+                ((one + x).hypot(y).ln() - one_minus_x.hypot(y).ln()) / two
+            } else {
+                (four * x / denominator).ln_1p() / four
+            };
+
+            (real, (two * y).atan2(one_minus_x * (one + x) - y * y) / two)
+        };
+
+        return Self {
+            real: real.copysign(self.real),
+            imaginary: imaginary.copysign(self.imaginary)
+        };
+    }
+
+    pub fn atanh_assign(&mut self) {
+        *self = Self::atanh(self);
+    }
+}
+
+impl<N: Float+ConstZero+ConstOne> Complex<N> {
+    pub const ZERO: Self = Self::new(N::ZERO, N::ZERO);
+    pub const ONE: Self = Self::new(N::ONE, N::ZERO);
+    pub const I: Self = Self::new(N::ZERO, N::ONE);
+}
+
+// Counterparts of the basic operations usable in `const` and `static` items. Floating-point
+// arithmetic is only const-evaluable for concrete types, hence the separate names.
+macro_rules! impl_const_ops {
+    ($($float:ty),*) => {
+        $(
+            impl Complex<$float> {
+                pub const fn const_from_real(real: $float) -> Self {
+                    return Self::new(real, 0.0);
+                }
+
+                pub const fn const_conj(&self) -> Self {
+                    return Self::new(self.real, -self.imaginary);
+                }
+
+                pub const fn const_neg(&self) -> Self {
+                    return Self::new(-self.real, -self.imaginary);
+                }
+
+                pub const fn const_add(&self, other: &Self) -> Self {
+                    return Self::new(self.real + other.real, self.imaginary + other.imaginary);
+                }
+
+                pub const fn const_sub(&self, other: &Self) -> Self {
+                    return Self::new(self.real - other.real, self.imaginary - other.imaginary);
+                }
+
+                /// Textbook product, without the infinity and NaN recovery done by `mul`.
+                pub const fn const_mul(&self, other: &Self) -> Self {
+                    return Self::new(
+                        self.real * other.real - self.imaginary * other.imaginary,
+                        self.real * other.imaginary + self.imaginary * other.real
+                    );
+                }
+
+                pub const fn const_mulf(&self, other: $float) -> Self {
+                    return Self::new(self.real * other, self.imaginary * other);
+                }
+            }
+        )*
+    };
+}
+
+impl_const_ops!(f32, f64);
+
 impl<N: Float> Default for Complex<N> {
     fn default() -> Self {
         return Self::zero();
@@ -488,12 +1439,566 @@ impl<N: Float+Debug> Debug for Complex<N> {
     }
 }
 
-impl<N: Float+Display> Display for Complex<N> {
-    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        let rsign = if self.real.is_sign_negative() { '-' } else { '+' };
-        let isign = if self.imaginary.is_sign_negative() { '-' } else { '+' };
 
-        return write!(formatter, "{rsign} {} {isign} {}i", self.real.abs(), self.imaginary.abs());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type C = Complex<f64>;
+
+    fn assert_close(actual: C, expected: C, tolerance: f64) {
+        for (a, e) in [(actual.real, expected.real), (actual.imaginary, expected.imaginary)] {
+            assert!((a - e).abs() <= tolerance * e.abs(), "{actual:?} != {expected:?}");
+        }
+    }
+
+    // Exact comparison for special values: NaN matches NaN, and zeros must agree in sign.
+    fn assert_special(actual: C, expected: C) {
+        for (a, e) in [(actual.real, expected.real), (actual.imaginary, expected.imaginary)] {
+            let same = if e.is_nan() { a.is_nan() } else { a == e && a.is_sign_negative() == e.is_sign_negative() };
+
+            assert!(same, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn annex_g_multiplication() {
+        let (inf, nan) = (f64::INFINITY, f64::NAN);
+
+        assert_special(Complex::mul(&C::new(inf, 0.0), &C::new(1.0, 0.0)), C::new(inf, 0.0));
+        assert_special(Complex::mul(&C::new(1.0, 0.0), &C::new(inf, 0.0)), C::new(inf, 0.0));
+        assert_special(Complex::mul(&C::new(inf, nan), &C::new(1.0, 1.0)), C::new(inf, inf));
+        assert_special(Complex::mul(&C::new(1.0, 1.0), &C::new(nan, -inf)), C::new(inf, -inf));
+        assert_special(Complex::mul(&C::new(inf, inf), &C::new(0.0, 1.0)), C::new(-inf, inf));
+        assert_special(Complex::mul(&C::new(1e300, 1e300), &C::new(1e300, -1e300)), C::new(inf, nan));
+        assert!(Complex::mul(&C::new(inf, 0.0), &C::new(0.0, 0.0)).has_nan());
+        assert!(Complex::mul(&C::new(nan, 1.0), &C::new(2.0, 0.0)).is_nan());
+    }
+
+    #[test]
+    fn annex_g_division() {
+        let (inf, nan) = (f64::INFINITY, f64::NAN);
+
+        assert_special(Complex::div(&C::new(1.0, 0.0), &C::new(0.0, 0.0)), C::new(inf, nan));
+        assert_special(Complex::div(&C::new(-1.0, 2.0), &C::new(-0.0, 0.0)), C::new(inf, -inf));
+        assert_special(Complex::div(&C::new(inf, 0.0), &C::new(1.0, 1.0)), C::new(inf, -inf));
+        assert_special(Complex::div(&C::new(inf, nan), &C::new(1.0, 1.0)), C::new(inf, -inf));
+        assert_special(Complex::div(&C::new(1.0, 1.0), &C::new(inf, 0.0)), C::new(0.0, 0.0));
+        assert_special(Complex::div(&C::new(1.0, 1.0), &C::new(nan, -inf)), C::new(-0.0, 0.0));
+        assert_special(Complex::div(&C::new(-1.0, 1.0), &C::new(2.0, 0.0)), C::new(-0.5, 0.5));
+        assert!(Complex::div(&C::new(0.0, 0.0), &C::new(0.0, 0.0)).is_nan());
+        assert!(Complex::div(&C::new(inf, 0.0), &C::new(inf, 0.0)).is_nan());
+    }
+
+    #[test]
+    fn annex_g_exp_ln() {
+        let (inf, nan, pi) = (f64::INFINITY, f64::NAN, std::f64::consts::PI);
+
+        for (real, imaginary) in [(0.0, 0.0), (0.0, -0.0), (-0.0, 0.0), (-0.0, -0.0)] {
+            assert_special(C::new(real, imaginary).exp(), C::new(1.0, imaginary));
+        }
+
+        assert_eq!(C::new(-inf, nan).exp(), C::new(0.0, 0.0));
+        assert_eq!(C::new(-inf, inf).exp(), C::new(0.0, 0.0));
+        assert_special(C::new(inf, nan).exp(), C::new(inf, nan));
+        assert_special(C::new(inf, -0.0).exp(), C::new(inf, -0.0));
+        assert_special(C::new(nan, 0.0).exp(), C::new(nan, 0.0));
+
+        assert_special(C::new(-0.0, 0.0).ln(), C::new(-inf, pi));
+        assert_special(C::new(-0.0, -0.0).ln(), C::new(-inf, -pi));
+        assert_special(C::new(0.0, -0.0).ln(), C::new(-inf, -0.0));
+        assert_special(C::new(-inf, 0.0).ln(), C::new(inf, pi));
+        assert_special(C::new(nan, inf).ln(), C::new(inf, nan));
+    }
+
+    #[test]
+    fn classify() {
+        let (inf, nan, tiny) = (f64::INFINITY, f64::NAN, f64::MIN_POSITIVE / 2.0);
+
+        assert_eq!(C::new(0.0, -0.0).classify(), FpCategory::Zero);
+        assert_eq!(C::new(tiny, 0.0).classify(), FpCategory::Subnormal);
+        assert_eq!(C::new(1.0, tiny).classify(), FpCategory::Subnormal);
+        assert_eq!(C::new(1.0, 0.0).classify(), FpCategory::Normal);
+        assert_eq!(C::new(f64::MIN_POSITIVE, -1e300).classify(), FpCategory::Normal);
+        assert_eq!(C::new(inf, 0.0).classify(), FpCategory::Infinite);
+        assert_eq!(C::new(nan, -inf).classify(), FpCategory::Infinite);
+        assert_eq!(C::new(nan, 1.0).classify(), FpCategory::Nan);
+        assert_eq!(C::new(0.0, nan).classify(), FpCategory::Nan);
+
+        assert!(C::new(1.0, tiny).is_subnormal() && !C::new(1.0, tiny).is_normal());
+        assert!(C::new(nan, inf).is_infinite() && !C::new(nan, inf).is_nan());
+        assert!(!C::new(inf, 1.0).is_finite());
+    }
+
+    #[test]
+    fn exp_m1_ln_1p() {
+        // Reference values computed with mpmath; exp(z) - 1 and ln(1 + z) lose every digit here.
+        assert_close(C::new(1e-10, 1e-10).exp_m1(), C::new(1e-10, 1.0000000001000001e-10), 1e-15);
+        assert_close(C::new(-1e-20, 3e-12).exp_m1(), C::new(-1.00045e-20, 3e-12), 1e-14);
+        assert_close(C::new(2e-9, -1e-8).exp_m1(), C::new(1.999999952e-09, -1.000000002e-08), 1e-15);
+        assert_close(C::new(0.3, 0.4).exp_m1(), C::new(0.24330229506950257, 0.5256597791969788), 1e-15);
+        assert_special(C::new(-0.0, 0.0).exp_m1(), C::new(-0.0, 0.0));
+
+        assert_close(C::new(1e-10, 1e-10).ln_1p(), C::new(1e-10, 9.999999999e-11), 1e-15);
+        assert_close(C::new(-1e-20, 3e-12).ln_1p(), C::new(-9.9955e-21, 3e-12), 1e-14);
+        assert_close(C::new(2e-9, -1e-8).ln_1p(), C::new(2.000000048e-09, -9.99999998e-09), 1e-15);
+        assert_close(C::new(0.3, 0.4).ln_1p(), C::new(0.30759281954511675, 0.2984989315861793), 1e-15);
+        assert_special(C::new(-0.0, -0.0).ln_1p(), C::new(-0.0, -0.0));
+    }
+
+    #[test]
+    fn exp2_log2_log10() {
+        assert_eq!(C::new(3.0, 0.0).exp2(), C::new(8.0, 0.0));
+        assert_close(C::new(1.0, 1e-20).exp2(), C::new(2.0, 1.3862943611198905e-20), 1e-15);
+        // A large imaginary part needs the extra precision in y ln(2).
+        assert_close(C::new(0.5, 1000.0).exp2(), C::new(-0.5843983241661524, 1.287819319125083), 4e-16);
+        assert_close(C::new(-3.0, 12345.678).exp2(), C::new(0.11839671491192014, -0.040090122200680645), 4e-16);
+        assert!(C::new(f64::INFINITY, 1.0).exp2().is_infinite());
+
+        assert_close(C::new(3.0, 4.0).log2(), C::new(2.321928094887362, 1.3378042124509761), 1e-15);
+        assert_close(C::new(-1e-300, 1e300).log2(), C::new(996.5784284662087, 2.266180070913597), 1e-15);
+        assert_eq!(C::new(1024.0, 0.0).log2(), C::new(10.0, 0.0));
+        assert_close(C::new(3.0, 4.0).log10(), C::new(0.6989700043360189, 0.40271919627337316), 1e-15);
+        assert_close(C::new(-1e-300, 1e300).log10(), C::new(300.0, 0.6821881769209207), 1e-15);
+        assert_eq!(C::new(1000.0, 0.0).log10(), C::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn mul_accurate() {
+        let epsilon = 2f64.powi(-30);
+
+        // (1 + e)(1 - e) = 1 - e^2 rounds to 1, so the textbook real part cancels to 0.
+        let (a, b) = (C::new(1.0 + epsilon, 1.0), C::new(1.0 - epsilon, 1.0));
+        assert_eq!(Complex::mul(&a, &b), C::new(0.0, 2.0));
+        assert_eq!(a.mul_accurate(&b), C::new(-epsilon * epsilon, 2.0));
+
+        let (a, b) = (C::new(1.0 + epsilon, -1.0), C::new(1.0, 1.0 - epsilon));
+        assert_eq!(Complex::mul(&a, &b), C::new(2.0, 0.0));
+        assert_eq!(a.mul_accurate(&b), C::new(2.0, -epsilon * epsilon));
+
+        let (a, b) = (C::new(1.0 + epsilon, 1.0), C::new(1.0 - epsilon, -1.0));
+        assert_eq!(a.mul_accurate(&b), C::new(2.0 - epsilon * epsilon, -2.0 * epsilon));
+
+        let mut product = C::new(1.0 + epsilon, 1.0);
+        product.mul_accurate_assign(&C::new(1.0 - epsilon, 1.0));
+        assert_eq!(product, C::new(-epsilon * epsilon, 2.0));
+
+        // Non-finite results fall back to the Annex G rules of `mul`.
+        assert_eq!(C::new(f64::INFINITY, 0.0).mul_accurate(&C::new(1.0, 0.0)), C::new(f64::INFINITY, 0.0));
+    }
+
+    #[test]
+    fn mul_add() {
+        let epsilon = 2f64.powi(-30);
+        let z = C::new(1.0 + epsilon, 1.0);
+
+        assert_eq!(Complex::mul_add(&z, &C::new(1.0 - epsilon, 1.0), &C::new(1.0, -2.0)), C::new(1.0 - epsilon * epsilon, 0.0));
+        assert_eq!(MulAdd::mul_add(C::new(2.0, 3.0), C::new(4.0, -5.0), C::new(1.0, 1.0)), C::new(24.0, 3.0));
+
+        let mut sum = C::new(2.0, 3.0);
+        sum.mul_add_assign(&C::new(4.0, -5.0), &C::new(1.0, 1.0));
+        assert_eq!(sum, C::new(24.0, 3.0));
+    }
+
+    #[test]
+    fn ln_branch() {
+        let tau = std::f64::consts::TAU;
+        let z = C::new(-1.0, 1.0);
+        let ln = z.ln();
+
+        assert_eq!(z.ln_branch(0), ln);
+        for k in [-3, -1, 1, 2] {
+            assert_eq!(z.ln_branch(k), C::new(ln.real, ln.imaginary + tau * k as f64));
+        }
+
+        // Odd sheets of the square root are the negated principal root.
+        let z = C::new(3.0, -4.0);
+        assert_close(z.powf_branch(0.5, 0), C::new(2.0, -1.0), 1e-15);
+        assert_close(z.powf_branch(0.5, 1), C::new(-2.0, 1.0), 1e-15);
+        assert_close(z.powf_branch(0.5, -2), C::new(2.0, -1.0), 1e-14);
+        assert_close(z.powf_branch(1.0 / 3.0, 1), z.roots(3).nth(1).unwrap(), 1e-15);
+    }
+
+    #[test]
+    fn cast() {
+        let z = C::new(0.1, -2.5);
+
+        assert_eq!(z.cast::<f32>(), Some(Complex::new(0.1f32, -2.5)));
+        assert_eq!(Complex::new(0.1f32, -2.5).cast::<f64>(), Some(C::new(0.1f32 as f64, -2.5)));
+        assert_eq!(C::new(1e300, 1.0).cast::<f32>(), None);
+        assert_eq!(C::new(1.0, -f64::MAX).cast::<f32>(), None);
+        assert_eq!(C::new(1e-50, 1.0).cast::<f32>(), Some(Complex::new(0.0f32, 1.0)));
+        assert_eq!(C::new(f64::INFINITY, 1.0).cast::<f32>(), Some(Complex::new(f32::INFINITY, 1.0)));
+
+        let nan = C::new(f64::NAN, 1.0).cast::<f32>().unwrap();
+        assert!(nan.real.is_nan() && nan.imaginary == 1.0);
+    }
+
+    #[test]
+    fn as_complex() {
+        assert_eq!(C::new(0.1, -2.5).as_complex::<f32>(), Complex::new(0.1f32, -2.5));
+        assert_eq!(C::new(1e300, -1e300).as_complex::<f32>(), Complex::new(f32::INFINITY, f32::NEG_INFINITY));
+        assert_eq!(Complex::new(0.1f32, 3.0).as_complex::<f64>(), C::new(0.1f32 as f64, 3.0));
+        assert!(C::new(f64::NAN, 0.0).as_complex::<f32>().real.is_nan());
+
+        // `as_` is still the `AsPrimitive` conversion of the real part.
+        let real: i64 = C::new(1.75, 2.0).as_();
+        assert_eq!(real, 1);
+    }
+
+    #[test]
+    fn const_evaluation() {
+        const A: C = C::new(1.5, -2.0);
+        const B: C = C::const_from_real(4.0);
+        const SUM: C = A.const_add(&B);
+        const DIFFERENCE: C = A.const_sub(&B);
+        const PRODUCT: C = A.const_mul(&C::new(3.0, 4.0));
+        const SCALED: C = A.const_mulf(2.0).const_neg().const_conj();
+        static ROTATED: Complex<f32> = Complex::new(1.0f32, 2.0).const_mul(&Complex::<f32>::I);
+
+        assert_eq!(SUM, C::new(5.5, -2.0));
+        assert_eq!(DIFFERENCE, C::new(-2.5, -2.0));
+        assert_eq!(PRODUCT, Complex::mul(&A, &C::new(3.0, 4.0)));
+        assert_eq!(PRODUCT, C::new(12.5, 0.0));
+        assert_eq!(SCALED, C::new(-3.0, -4.0));
+        assert_eq!(ROTATED, Complex::new(-2.0f32, 1.0));
+        assert_eq!((C::ZERO, C::ONE, C::I), (C::new(0.0, 0.0), C::new(1.0, 0.0), C::new(0.0, 1.0)));
+    }
+
+    #[test]
+    fn layout() {
+        use std::mem::{align_of, size_of};
+
+        assert_eq!(size_of::<C>(), size_of::<[f64; 2]>());
+        assert_eq!(align_of::<C>(), align_of::<f64>());
+        assert_eq!(size_of::<Complex<f32>>(), 8);
+        assert_eq!(align_of::<Complex<f32>>(), align_of::<f32>());
+    }
+
+    #[test]
+    fn array_views() {
+        let mut z = C::new(1.5, -2.0);
+
+        assert_eq!(z.to_array(), [1.5, -2.0]);
+        assert_eq!(z.as_array(), &[1.5, -2.0]);
+        assert_eq!(std::ptr::addr_of!(z.imaginary), &z.as_array()[1] as *const f64);
+
+        z.as_array_mut()[0] = 3.0;
+        z.as_array_mut()[1] *= 2.0;
+        assert_eq!(z, C::new(3.0, -4.0));
+        assert_eq!((z[false], z[true], z.len()), (3.0, -4.0, 2));
+    }
+
+    #[test]
+    fn interleaved_views() {
+        let mut values = [C::new(1.0, 2.0), C::new(3.0, 4.0), C::new(5.0, 6.0)];
+
+        assert_eq!(C::as_interleaved(&values), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(C::from_interleaved(C::as_interleaved(&values)), Some(&values[..]));
+
+        C::as_interleaved_mut(&mut values)[3] = -4.0;
+        assert_eq!(values[1], C::new(3.0, -4.0));
+
+        let mut parts = [1.0f32, 2.0, 3.0, 4.0];
+        assert_eq!(Complex::from_interleaved(&parts), Some(&[Complex::new(1.0f32, 2.0), Complex::new(3.0, 4.0)][..]));
+
+        let complex = Complex::from_interleaved_mut(&mut parts).unwrap();
+        complex[1] = complex[1].conj();
+        assert_eq!(parts, [1.0, 2.0, 3.0, -4.0]);
+
+        assert_eq!(C::from_interleaved(&[1.0, 2.0, 3.0]), None);
+        assert_eq!(C::from_interleaved_mut(&mut [1.0]), None);
+        assert_eq!(C::from_interleaved(&[]), Some(&[][..]));
+        assert_eq!(C::from_interleaved_mut(&mut []).map(|values| values.len()), Some(0));
+        assert!(C::as_interleaved(&[]).is_empty());
+    }
+
+    #[test]
+    fn sin() {
+        let expected = C::new(1f64.sin() * 2f64.cosh(), 1f64.cos() * 2f64.sinh());
+
+        assert_close(C::new(1.0, 2.0).sin(), expected, 1e-15);
+        assert_close(C::new(1.0, -2.0).sin(), expected.conj(), 1e-15);
+    }
+
+    #[test]
+    fn sin_cos_small_arguments() {
+        // Forming e^y - e^-y would cancel here and lose most of the digits.
+        let z = C::new(1e-8, 1e-8);
+
+        assert_close(z.sin(), C::new(1e-8, 1e-8), 1e-15);
+        assert_close(z.cos(), C::new(1.0, -1e-16), 1e-15);
+        assert_close(C::new(1.0, 1e-10).sin(), C::new(1f64.sin(), 1f64.cos() * 1e-10), 1e-15);
+        assert_close(C::new(1.0, 1e-10).cos(), C::new(1f64.cos(), -(1f64.sin()) * 1e-10), 1e-15);
+    }
+
+    #[test]
+    fn sin_cos_signed_zeros() {
+        let sin = C::new(-0.0, 0.0).sin();
+        assert!(sin.real == 0.0 && sin.real.is_sign_negative() && sin.imaginary == 0.0 && sin.imaginary.is_sign_positive());
+
+        let cos = C::new(0.0, 0.0).cos();
+        assert!(cos.real == 1.0 && cos.imaginary == 0.0 && cos.imaginary.is_sign_negative());
+        assert_eq!(C::new(0.0, 2.0).sin(), C::new(0.0, 2f64.sinh()));
+    }
+
+    #[test]
+    fn tan_large_imaginary() {
+        assert_eq!(C::new(1.0, 800.0).tan(), C::new(0.0, 1.0));
+        assert_eq!(C::new(1.0, -800.0).tan(), C::new(0.0, -1.0));
+        assert_eq!(C::new(0.0, 1e300).tan(), C::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn sec_csc_large_imaginary() {
+        // sec(x + iy) ~ 2e^-|y| (cos x + i sgn(y) sin x), csc(x + iy) ~ 2e^-|y| (sin x - i sgn(y) cos x)
+        let scale = 2.0 * (-720f64).exp();
+        let (sin, cos) = 1f64.sin_cos();
+
+        assert_close(C::new(1.0, 720.0).sec(), C::new(scale * cos, scale * sin), 1e-9);
+        assert_close(C::new(1.0, -720.0).sec(), C::new(scale * cos, -scale * sin), 1e-9);
+        assert_close(C::new(1.0, 720.0).csc(), C::new(scale * sin, -scale * cos), 1e-9);
+        assert_close(C::new(1.0, -720.0).csc(), C::new(scale * sin, scale * cos), 1e-9);
+        assert_eq!(C::new(1.0, 1e300).sec(), C::new(0.0, 0.0));
+        assert!(!C::new(1.0, 1e300).csc().has_nan());
     }
-}
 
+    #[test]
+    fn inverse_trigonometric_branch_cuts() {
+        use std::f64::consts::FRAC_PI_2;
+
+        let acosh_2 = 1.3169578969248168;
+        let atanh_real = 3f64.ln() / 2.0;
+
+        assert_close(C::new(2.0, 0.0).asin(), C::new(FRAC_PI_2, acosh_2), 1e-15);
+        assert_close(C::new(2.0, -0.0).asin(), C::new(FRAC_PI_2, -acosh_2), 1e-15);
+        assert_close(C::new(-2.0, 0.0).asin(), C::new(-FRAC_PI_2, acosh_2), 1e-15);
+        assert_close(C::new(2.0, 0.0).acos(), C::new(0.0, -acosh_2), 1e-15);
+        assert_close(C::new(2.0, -0.0).acos(), C::new(0.0, acosh_2), 1e-15);
+        assert_close(C::new(-2.0, 0.0).acos(), C::new(std::f64::consts::PI, -acosh_2), 1e-15);
+        assert_close(C::new(0.0, 2.0).atan(), C::new(FRAC_PI_2, atanh_real), 1e-15);
+        assert_close(C::new(-0.0, 2.0).atan(), C::new(-FRAC_PI_2, atanh_real), 1e-15);
+        assert_close(C::new(0.0, -2.0).atan(), C::new(FRAC_PI_2, -atanh_real), 1e-15);
+    }
+
+    #[test]
+    fn inverse_trigonometric_near_one() {
+        // Reference values computed with mpmath.
+        assert_close(C::new(1.0, 1e-10).asin(), C::new(1.5707863267948967, 1.0000000000083334e-05), 1e-15);
+        assert_close(C::new(1.0, 1e-10).acos(), C::new(9.999999999916666e-06, -1.0000000000083334e-05), 1e-15);
+        assert_close(C::new(1.0 + 2f64.powi(-40), 1e-20).asin(), C::new(1.5707963267948892, 1.3486991523485068e-06), 1e-15);
+        assert_close(C::new(1.0 + 2f64.powi(-40), 1e-20).acos(), C::new(7.414552001892966e-15, -1.3486991523485068e-06), 1e-14);
+        assert_close(C::new(-1.0 + 2f64.powi(-40), 2f64.powi(-60)).asin(), C::new(-1.5707949780957442, 6.431098710769474e-13), 1e-15);
+        assert_close(C::new(-1.0 + 2f64.powi(-40), 2f64.powi(-60)).acos(), C::new(3.1415913048906408, -6.431098710769474e-13), 1e-15);
+        assert_close(C::new(0.9999999999, 0.0).asin(), C::new(1.5707821846586878, 0.0), 1e-15);
+        assert_close(C::new(0.9999999999, 0.0).acos(), C::new(1.4142136208911564e-05, 0.0), 1e-14);
+        assert_eq!(C::new(1.0, 0.0).asin(), C::new(std::f64::consts::FRAC_PI_2, 0.0));
+        assert_eq!(C::new(1.0, 0.0).acos(), C::new(0.0, -0.0));
+        assert_close(C::new(1e-10, 1.0).atan(), C::new(0.7853981634224483, 11.8594990552502), 1e-14);
+        assert_close(C::new(2f64.powi(-60), 1.0 - 2f64.powi(-40)).atan(), C::new(4.768371582031973e-07, 14.209517201478423), 1e-14);
+    }
+
+    #[test]
+    fn inverse_trigonometric_huge_arguments() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+        // Squaring these arguments would overflow.
+        assert_close(C::new(1e300, 1e300).asin(), C::new(FRAC_PI_4, 691.8152486690536), 1e-15);
+        assert_close(C::new(1e300, 1e300).acos(), C::new(FRAC_PI_4, -691.8152486690536), 1e-15);
+        assert_close(C::new(-1e300, 2e300).asin(), C::new(-0.4636476090008061, 692.2733940349907), 1e-15);
+        assert_close(C::new(-1e300, 2e300).acos(), C::new(2.0344439357957027, -692.2733940349907), 1e-15);
+        assert_close(C::new(1e300, 1.0).asin(), C::new(FRAC_PI_2, 691.4686750787737), 1e-15);
+        assert_close(C::new(1e300, 1.0).acos(), C::new(1e-300, -691.4686750787737), 1e-15);
+        assert_close(C::new(1e300, 1e300).atan(), C::new(FRAC_PI_2, 5e-301), 1e-15);
+        assert_close(C::new(-1e300, 2e300).atan(), C::new(-FRAC_PI_2, 3.9999999999999994e-301), 1e-15);
+        assert_close(C::new(0.0, 1e300).atan(), C::new(FRAC_PI_2, 1e-300), 1e-15);
+    }
+
+    #[test]
+    fn atan2() {
+        assert_eq!(C::new(1.0, 0.0).atan2(&C::new(-1.0, 0.0)), C::new(std::f64::consts::PI * 0.75, 0.0));
+        assert_eq!(C::new(-0.0, 0.0).atan2(&C::new(-1.0, 0.0)), C::new(-std::f64::consts::PI, 0.0));
+
+        // For complex arguments atan2(y, x) agrees with atan(y / x) when Re x > 0.
+        let (y, x) = (C::new(0.5, 1.5), C::new(2.0, -0.25));
+        assert_close(y.atan2(&x), Complex::div(&y, &x).atan(), 1e-15);
+    }
+
+    #[test]
+    fn inverse_hyperbolic_branch_cuts() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        let acosh_2 = 1.3169578969248168;
+        let atanh_real = 3f64.ln() / 2.0;
+
+        assert_close(C::new(-2.0, 0.0).acosh(), C::new(acosh_2, PI), 1e-15);
+        assert_close(C::new(-2.0, -0.0).acosh(), C::new(acosh_2, -PI), 1e-15);
+        assert_close(C::new(2.0, 0.0).atanh(), C::new(atanh_real, FRAC_PI_2), 1e-15);
+        assert_close(C::new(2.0, -0.0).atanh(), C::new(atanh_real, -FRAC_PI_2), 1e-15);
+        assert_close(C::new(0.0, 2.0).asinh(), C::new(acosh_2, FRAC_PI_2), 1e-15);
+        assert_close(C::new(-0.0, 2.0).asinh(), C::new(-acosh_2, FRAC_PI_2), 1e-15);
+        assert_close(C::new(0.0, -2.0).asinh(), C::new(acosh_2, -FRAC_PI_2), 1e-15);
+    }
+
+    #[test]
+    fn inverse_hyperbolic_round_trips() {
+        let z = C::new(0.5, -1.25);
+
+        assert_close(z.asinh().sinh(), z, 1e-15);
+        assert_close(z.acosh().cosh(), z, 1e-15);
+        assert_close(z.atanh().tanh(), z, 1e-15);
+    }
+
+    #[test]
+    fn tanh() {
+        // tanh(x + iy) = (sinh 2x + i sin 2y) / (cosh 2x + cos 2y)
+        let denominator = 2f64.cosh() + 2f64.cos();
+        assert_close(C::new(1.0, 1.0).tanh(), C::new(2f64.sinh() / denominator, 2f64.sin() / denominator), 1e-15);
+
+        // sinh(x) / cosh(x) would be inf / inf here.
+        assert_eq!(C::new(1000.0, 1.0).tanh(), C::new(1.0, 0.0));
+        assert_eq!(C::new(-1000.0, 1.0).tanh(), C::new(-1.0, 0.0));
+        assert_close(C::new(30.0, 1.0).tanh(), C::new(1.0, 4.0 * 1f64.sin() * 1f64.cos() * (-60f64).exp()), 1e-14);
+
+        // At the pole the result is i tan(y) of the rounded argument, not NaN.
+        let pole = std::f64::consts::FRAC_PI_2;
+        assert_eq!(C::new(0.0, pole).tanh(), C::new(0.0, pole.tan()));
+    }
+
+    #[test]
+    fn sqrt_branch_cut() {
+        assert_eq!(C::new(-4.0, 0.0).sqrt(), C::new(0.0, 2.0));
+        assert_eq!(C::new(-4.0, -0.0).sqrt(), C::new(0.0, -2.0));
+        assert_eq!(C::new(4.0, -0.0).sqrt(), C::new(2.0, -0.0));
+        assert_eq!(C::new(0.0, 8.0).sqrt(), C::new(2.0, 2.0));
+        assert_eq!(C::new(3.0, -4.0).sqrt(), C::new(2.0, -1.0));
+        assert_eq!(C::new(-3.0, -4.0).sqrt(), C::new(1.0, -2.0));
+
+        let zero = C::new(-0.0, -0.0).sqrt();
+        assert!(zero.real == 0.0 && zero.real.is_sign_positive() && zero.imaginary.is_sign_negative());
+        assert_eq!(C::new(-f64::INFINITY, 1.0).sqrt(), C::new(0.0, f64::INFINITY));
+        assert_eq!(C::new(1.0, -f64::INFINITY).sqrt(), C::new(f64::INFINITY, -f64::INFINITY));
+    }
+
+    #[test]
+    fn sqrt_extreme_magnitudes() {
+        // Reference values computed with mpmath.
+        let max = f64::MAX;
+
+        assert_close(C::new(max, max).sqrt(), C::new(1.4730945569055652e+154, 6.1017574412827024e+153), 1e-15);
+        assert_close(C::new(-max, max).sqrt(), C::new(6.1017574412827024e+153, 1.4730945569055652e+154), 1e-15);
+        assert_close(C::new(-max, 0.0).sqrt(), C::new(0.0, max.sqrt()), 1e-15);
+        assert_close(C::new(1e300, -1e-300).sqrt(), C::new(1e150, 0.0), 1e-15);
+        assert_close(C::new(5e-324, 5e-324).sqrt(), C::new(2.4421097261308304e-162, 1.0115549693666347e-162), 1e-15);
+        assert_close(C::new(-3e-320, 4e-320).sqrt(), C::new(9.99994433575849e-161, 1.999988867151698e-160), 1e-15);
+        assert_eq!(C::new(5e-324, 0.0).sqrt(), C::new(2f64.powi(-537), 0.0));
+    }
+
+    #[test]
+    fn cbrt() {
+        let root_3 = 3f64.sqrt();
+
+        assert_eq!(C::new(8.0, 0.0).cbrt(), C::new(2.0, 0.0));
+        assert_close(C::new(-8.0, 0.0).cbrt(), C::new(1.0, root_3), 1e-15);
+        assert_close(C::new(-8.0, -0.0).cbrt(), C::new(1.0, -root_3), 1e-15);
+        assert_close(C::new(0.0, 8.0).cbrt(), C::new(root_3, 1.0), 1e-15);
+        assert_close(C::new(1e300, 1e300).cbrt(), C::new(1.0842150814913511e+100, 2.9051455550725147e+99), 1e-15);
+        assert_close(C::new(-1.0, 1.0).cbrt().powu(3), C::new(-1.0, 1.0), 1e-15);
+    }
+
+    #[test]
+    fn div_extreme_magnitudes() {
+        let big = C::new(1e300, 1e300);
+
+        assert_eq!(Complex::div(&big, &big), C::new(1.0, 0.0));
+
+        // Test cases from Baudin and Smith, "A Robust Complex Division in Scilab".
+        let result = Complex::div(&C::new(1.0, 1.0), &C::new(1.0, 2f64.powi(1023)));
+        assert_eq!(result, C::new(2f64.powi(-1023), -(2f64.powi(-1023))));
+
+        let result = Complex::div(&C::new(2f64.powi(1023), 2f64.powi(-1023)), &C::new(2f64.powi(677), 2f64.powi(-677)));
+        assert_eq!(result, C::new(2f64.powi(346), -(2f64.powi(-1008))));
+    }
+
+    #[test]
+    fn div_subnormal() {
+        let tiny = f64::from_bits(1);
+
+        assert_eq!(Complex::div(&C::new(4.0 * tiny, 4.0 * tiny), &C::new(2.0 * tiny, 2.0 * tiny)), C::new(2.0, 0.0));
+        assert_close(Complex::div(&C::new(tiny, 2.0 * tiny), &C::new(2.0 * tiny, tiny)), C::new(0.8, 0.6), 1e-15);
+        assert_eq!(Complex::div(&C::new(3.0 * tiny, 0.0), &C::new(0.0, 3.0)), C::new(0.0, -tiny));
+        assert_eq!(C::new(0.0, 2f64.powi(-1022)).recip(), C::new(0.0, -(2f64.powi(1022))));
+    }
+
+    #[test]
+    fn amplitude_extreme_magnitudes() {
+        assert_eq!(C::new(1e300, 1e300).amplitude(), 1e300 * 2f64.sqrt());
+        assert_eq!(C::new(3e-320, 4e-320).amplitude(), 5e-320);
+    }
+
+    #[test]
+    fn powf_integral_exponents() {
+        assert_eq!(C::new(1.0, 1.0).powf(2.0), C::new(0.0, 2.0));
+        assert_eq!(Complex::pow(&C::new(1.0, 1.0), &C::new(2.0, 0.0)), C::new(0.0, 2.0));
+        assert_close(C::new(1.0000000001, 0.0).powf(1e10), C::new(2.7182820532347874, 0.0), 1e-12);
+    }
+
+    #[test]
+    fn zero_base() {
+        let zero = C::new(0.0, 0.0);
+
+        assert_eq!(Complex::pow(&zero, &C::new(0.5, 3.0)), zero);
+        assert!(Complex::pow(&zero, &C::new(-0.5, 3.0)).is_infinite());
+        assert!(Complex::pow(&zero, &C::new(0.0, 3.0)).has_nan());
+        assert_eq!(zero.powf(2.5), zero);
+        assert!(zero.powf(-2.5).is_infinite());
+        assert_eq!(zero.powf(0.0), C::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn powi_powu() {
+        let z = C::new(1.0, 1.0);
+
+        assert_eq!(z.powu(0), C::new(1.0, 0.0));
+        assert_eq!(z.powu(1), z);
+        assert_eq!(z.powu(4), C::new(-4.0, 0.0));
+        assert_eq!(z.powu(5), C::new(-4.0, -4.0));
+        assert_eq!(z.powi(-2), C::new(0.0, -0.5));
+        assert_eq!(z.powi(-4), C::new(-0.25, 0.0));
+        assert_eq!(C::new(2.0, 0.0).powi(-3), C::new(0.125, 0.0));
+    }
+
+    #[test]
+    fn powers_of_zero() {
+        let zero = C::new(0.0, 0.0);
+        let infinity = C::new(f64::INFINITY, 0.0);
+
+        assert_eq!(zero.powu(0), C::new(1.0, 0.0));
+        assert_eq!(zero.powu(3), zero);
+        assert_eq!(zero.powi(0), C::new(1.0, 0.0));
+        assert_eq!(zero.powi(2), zero);
+
+        for n in [-1, -2, -7, isize::MIN] {
+            assert_special(zero.powi(n), infinity);
+            assert_special(zero.powf(n as f64), infinity);
+            assert_special(C::new(-0.0, 0.0).powi(n), infinity);
+        }
+    }
+
+    #[test]
+    fn powers_at_isize_min() {
+        let n = isize::MIN;
+
+        assert_eq!(C::new(1.0, 0.0).powi(n), C::new(1.0, 0.0));
+        assert_eq!(C::new(-1.0, 0.0).powi(n), C::new(1.0, 0.0));
+        assert_eq!(C::new(0.0, 1.0).powi(n).real, 1.0);
+        assert_eq!(C::new(1.0, 0.0).powf(n as f64), C::new(1.0, 0.0));
+
+        // 2^n underflows to zero and 0.5^n overflows, either way round.
+        assert!(C::new(2.0, 0.0).powi(n).is_zero());
+        assert!(C::new(2.0, 0.0).powf(n as f64).is_zero());
+        assert_eq!(C::new(0.5, 0.0).powi(n), C::new(f64::INFINITY, 0.0));
+        assert_eq!(C::new(0.5, 0.0).powf(n as f64), C::new(f64::INFINITY, 0.0));
+        assert_eq!(C::new(2.0, 0.0).powu(n.unsigned_abs()).real, f64::INFINITY);
+    }
+}
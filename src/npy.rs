@@ -0,0 +1,424 @@
+use super::Complex;
+use super::io_util::{invalid_data, read_length};
+use super::zip;
+
+use std::io::{self, Read, Seek, Write};
+
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+// The magic, version and header length prefix, plus the header, are padded to this.
+const ALIGNMENT: usize = 64;
+
+/// Complex element types that can be stored in .npy files, as the `c8` and `c16` dtypes.
+pub trait NpyElement: Sized {
+    /// Size of one element in bytes, the number in the dtype code.
+    const SIZE: usize;
+
+    fn decode(bytes: &[u8], big_endian: bool) -> Vec<Self>;
+
+    fn encode(values: &[Self], big_endian: bool) -> Vec<u8>;
+}
+
+macro_rules! impl_npy_element {
+    ($float:ty) => {
+        impl NpyElement for Complex<$float> {
+            const SIZE: usize = Self::BYTES;
+
+            fn decode(bytes: &[u8], big_endian: bool) -> Vec<Self> {
+                let values = if big_endian { Self::slice_from_be_bytes(bytes) } else { Self::slice_from_le_bytes(bytes) };
+
+                return values.unwrap();
+            }
+
+            fn encode(values: &[Self], big_endian: bool) -> Vec<u8> {
+                return if big_endian { Self::slice_to_be_bytes(values) } else { Self::slice_to_le_bytes(values) };
+            }
+        }
+    };
+}
+
+impl_npy_element!(f32);
+impl_npy_element!(f64);
+
+/// Array read from or written to a .npy file. `data` holds the elements in the order given
+/// by `fortran_order`: row-major (C) when false, column-major when true.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NpyArray<T> {
+    pub shape: Vec<usize>,
+    pub fortran_order: bool,
+    pub data: Vec<T>
+}
+
+impl<T: NpyElement> NpyArray<T> {
+    /// Row-major array with the given shape.
+    pub fn new(shape: Vec<usize>, data: Vec<T>) -> Self {
+        return Self {
+            shape: shape,
+            fortran_order: false,
+            data: data
+        };
+    }
+
+    fn element_count(shape: &[usize]) -> Option<usize> {
+        return shape.iter().try_fold(1usize, |count, &length| count.checked_mul(length));
+    }
+
+    pub fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut prefix = [0; 8];
+        reader.read_exact(&mut prefix)?;
+
+        if &prefix[..6] != MAGIC {
+            return Err(invalid_data("not a .npy file"));
+        }
+
+        let header_length = match prefix[6] {
+            1 => {
+                let mut length = [0; 2];
+                reader.read_exact(&mut length)?;
+                u16::from_le_bytes(length) as usize
+            },
+            2 | 3 => {
+                let mut length = [0; 4];
+                reader.read_exact(&mut length)?;
+                u32::from_le_bytes(length) as usize
+            },
+            _ => return Err(invalid_data("unsupported .npy format version"))
+        };
+
+        let header = read_length(&mut reader, header_length as u64)?;
+
+        // Versions 1 and 2 use latin-1 headers, but a valid dictionary is plain ASCII anyway.
+        let header = std::str::from_utf8(&header).map_err(|_| invalid_data("invalid .npy header encoding"))?;
+        let header = Header::parse(header)?;
+        let big_endian = match header.descr.as_bytes() {
+            [b'<', ..] => false,
+            [b'>', ..] => true,
+            [b'=', ..] => cfg!(target_endian = "big"),
+            _ => return Err(invalid_data("unsupported .npy dtype"))
+        };
+
+        if header.descr[1..] != format!("c{}", T::SIZE) {
+            return Err(invalid_data("unsupported .npy dtype"));
+        }
+
+        let count = Self::element_count(&header.shape).ok_or_else(|| invalid_data(".npy shape too large"))?;
+        let length = count.checked_mul(T::SIZE).ok_or_else(|| invalid_data(".npy shape too large"))?;
+        let data = read_length(&mut reader, length as u64)?;
+
+        return Ok(Self {
+            shape: header.shape,
+            fortran_order: header.fortran_order,
+            data: T::decode(&data, big_endian)
+        });
+    }
+
+    fn write_with<W: Write>(&self, mut writer: W, big_endian: bool) -> io::Result<()> {
+        if Self::element_count(&self.shape) != Some(self.data.len()) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "shape does not match the number of elements"));
+        }
+
+        let shape = match self.shape.as_slice() {
+            [length] => format!("({length},)"),
+            shape => format!("({})", shape.iter().map(usize::to_string).collect::<Vec<_>>().join(", "))
+        };
+        let order = if big_endian { '>' } else { '<' };
+        let fortran_order = if self.fortran_order { "True" } else { "False" };
+        let mut header = format!("{{'descr': '{order}c{}', 'fortran_order': {fortran_order}, 'shape': {shape}, }}", T::SIZE);
+
+        // Version 1 stores the header length in 2 bytes, version 2 in 4.
+        let prefix_length = if header.len() + 11 <= u16::MAX as usize { 10 } else { 12 };
+        let padded = (prefix_length + header.len() + 1).next_multiple_of(ALIGNMENT);
+
+        header.push_str(&" ".repeat(padded - prefix_length - header.len() - 1));
+        header.push('\n');
+
+        writer.write_all(MAGIC)?;
+
+        if prefix_length == 10 {
+            writer.write_all(&[1, 0])?;
+            writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        } else {
+            writer.write_all(&[2, 0])?;
+            writer.write_all(&(header.len() as u32).to_le_bytes())?;
+        }
+
+        writer.write_all(header.as_bytes())?;
+
+        return writer.write_all(&T::encode(&self.data, big_endian));
+    }
+
+    /// Writes the array with a little-endian dtype (`<c8` or `<c16`).
+    pub fn write<W: Write>(&self, writer: W) -> io::Result<()> {
+        return self.write_with(writer, false);
+    }
+
+    /// Writes the array with a big-endian dtype (`>c8` or `>c16`).
+    pub fn write_big_endian<W: Write>(&self, writer: W) -> io::Result<()> {
+        return self.write_with(writer, true);
+    }
+
+    /// Reads every array of an uncompressed .npz archive (as written by `numpy.savez`),
+    /// named without the `.npy` suffix.
+    pub fn read_npz<R: Read+Seek>(reader: R) -> io::Result<Vec<(String, Self)>> {
+        let mut arrays = Vec::new();
+
+        for (name, data) in zip::read_stored_entries(reader)? {
+            let name = name.strip_suffix(".npy").map(str::to_owned).unwrap_or(name);
+
+            arrays.push((name, Self::read(data.as_slice())?));
+        }
+
+        return Ok(arrays);
+    }
+
+    /// Writes an uncompressed .npz archive, storing each array as `<name>.npy`.
+    pub fn write_npz<W: Write>(writer: W, arrays: &[(&str, &Self)]) -> io::Result<()> {
+        let mut entries = Vec::with_capacity(arrays.len());
+
+        for (name, array) in arrays {
+            let mut data = Vec::new();
+            array.write(&mut data)?;
+
+            entries.push((format!("{name}.npy"), data));
+        }
+
+        return zip::write_stored_entries(writer, &entries);
+    }
+}
+
+// The header is the repr of a Python dict, e.g.
+// `{'descr': '<c16', 'fortran_order': False, 'shape': (3, 4), }`.
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>
+}
+
+impl Header {
+    fn parse(text: &str) -> io::Result<Self> {
+        let mut parser = HeaderParser {
+            text: text,
+            position: 0
+        };
+        let (mut descr, mut fortran_order, mut shape) = (None, None, None);
+
+        parser.expect('{')?;
+
+        while !parser.eat('}') {
+            let key = parser.string()?;
+            parser.expect(':')?;
+
+            match key.as_str() {
+                "descr" => descr = Some(parser.string()?),
+                "fortran_order" => fortran_order = Some(parser.boolean()?),
+                "shape" => shape = Some(parser.tuple()?),
+                _ => return Err(invalid_data("unexpected key in .npy header"))
+            }
+
+            if !parser.eat(',') {
+                parser.expect('}')?;
+                break;
+            }
+        }
+
+        return match (descr, fortran_order, shape) {
+            (Some(descr), Some(fortran_order), Some(shape)) => Ok(Self {
+                descr: descr,
+                fortran_order: fortran_order,
+                shape: shape
+            }),
+            _ => Err(invalid_data("missing key in .npy header"))
+        };
+    }
+}
+
+struct HeaderParser<'a> {
+    text: &'a str,
+    position: usize
+}
+
+impl<'a> HeaderParser<'a> {
+    fn rest(&self) -> &'a str {
+        return &self.text[self.position..];
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+
+        if self.rest().starts_with(expected) {
+            self.position += expected.len_utf8();
+            return true;
+        }
+
+        return false;
+    }
+
+    fn expect(&mut self, expected: char) -> io::Result<()> {
+        if !self.eat(expected) {
+            return Err(invalid_data("malformed .npy header"));
+        }
+
+        return Ok(());
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        self.skip_whitespace();
+
+        let quote = self.rest().chars().next().filter(|&c| c == '\'' || c == '"').ok_or_else(|| invalid_data("malformed .npy header"))?;
+        let rest = &self.rest()[1..];
+        let end = rest.find(quote).ok_or_else(|| invalid_data("malformed .npy header"))?;
+
+        self.position += end + 2;
+
+        return Ok(rest[..end].to_owned());
+    }
+
+    fn boolean(&mut self) -> io::Result<bool> {
+        self.skip_whitespace();
+
+        for (word, value) in [("True", true), ("False", false)] {
+            if self.rest().starts_with(word) {
+                self.position += word.len();
+                return Ok(value);
+            }
+        }
+
+        return Err(invalid_data("malformed .npy header"));
+    }
+
+    fn tuple(&mut self) -> io::Result<Vec<usize>> {
+        let mut values = Vec::new();
+
+        self.expect('(')?;
+
+        while !self.eat(')') {
+            self.skip_whitespace();
+
+            let digits = self.rest().bytes().take_while(u8::is_ascii_digit).count();
+            let value = self.rest()[..digits].parse().map_err(|_| invalid_data("malformed .npy header"))?;
+
+            self.position += digits;
+            // Python 2 wrote long integers with an `L` suffix.
+            let _ = self.eat('L');
+
+            values.push(value);
+
+            if !self.eat(',') {
+                self.expect(')')?;
+                break;
+            }
+        }
+
+        return Ok(values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    fn sample() -> NpyArray<Complex<f64>> {
+        let data = (0..6).map(|i| Complex::new(i as f64, -0.5 * i as f64)).collect();
+
+        return NpyArray::new(vec![2, 3], data);
+    }
+
+    fn round_trip<T: NpyElement>(array: &NpyArray<T>, big_endian: bool) -> NpyArray<T> {
+        let mut bytes = Vec::new();
+        array.write_with(&mut bytes, big_endian).unwrap();
+
+        assert_eq!((bytes.len() - array.data.len() * T::SIZE) % ALIGNMENT, 0);
+
+        return NpyArray::read(bytes.as_slice()).unwrap();
+    }
+
+    #[test]
+    fn write_read() {
+        let array = sample();
+        assert_eq!(round_trip(&array, false), array);
+
+        let single = NpyArray::new(vec![3], vec![Complex::new(1.5f32, -2.0), Complex::new(f32::INFINITY, 0.0), Complex::new(-0.0, 1e-40)]);
+        assert_eq!(round_trip(&single, false), single);
+    }
+
+    #[test]
+    fn write_big_endian() {
+        let array = sample();
+        let mut bytes = Vec::new();
+        array.write_big_endian(&mut bytes).unwrap();
+
+        assert!(bytes.windows(4).any(|window| window == b">c16"));
+        assert_eq!(NpyArray::read(bytes.as_slice()).unwrap(), array);
+    }
+
+    #[test]
+    fn fortran_order() {
+        let mut array = sample();
+        array.fortran_order = true;
+
+        assert_eq!(round_trip(&array, false), array);
+        assert_eq!(round_trip(&array, true), array);
+    }
+
+    #[test]
+    fn empty_shape() {
+        let scalar = NpyArray::new(vec![], vec![Complex::new(1.0, 2.0)]);
+        assert_eq!(round_trip(&scalar, false), scalar);
+
+        let empty: NpyArray<Complex<f64>> = NpyArray::new(vec![0, 4], vec![]);
+        assert_eq!(round_trip(&empty, false), empty);
+
+        let mismatched = NpyArray::new(vec![2], vec![Complex::new(1.0, 2.0)]);
+        assert_eq!(mismatched.write(Vec::new()).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn npz() {
+        let first = sample();
+        let second = NpyArray::new(vec![], vec![Complex::new(-1.0, 0.25)]);
+        let mut bytes = Vec::new();
+        NpyArray::write_npz(&mut bytes, &[("first", &first), ("second", &second)]).unwrap();
+
+        let arrays = NpyArray::read_npz(Cursor::new(bytes)).unwrap();
+        assert_eq!(arrays, vec![("first".to_owned(), first), ("second".to_owned(), second)]);
+    }
+
+    #[test]
+    fn corrupt_sizes() {
+        // A tiny file claiming an enormous shape must fail without allocating for it.
+        let header = "{'descr': '<c16', 'fortran_order': False, 'shape': (100000000000000,), }\n";
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&[0; 16]);
+
+        let error = NpyArray::<Complex<f64>>::read(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        // Likewise for a header length pointing past the end of the file.
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[2, 0]);
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+
+        let error = NpyArray::<Complex<f64>>::read(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        // And for an .npz central directory size larger than the archive.
+        let mut bytes = Vec::new();
+        NpyArray::write_npz(&mut bytes, &[("array", &sample())]).unwrap();
+        let size = bytes.len() - 22 + 12;
+        bytes[size..size + 4].copy_from_slice(&0x7fff_ffffu32.to_le_bytes());
+
+        let error = NpyArray::<Complex<f64>>::read_npz(Cursor::new(bytes)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
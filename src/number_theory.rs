@@ -0,0 +1,354 @@
+use super::GaussianInteger;
+
+use num_traits::{PrimInt, Signed};
+
+
+impl<N: PrimInt+Signed> GaussianInteger<N> {
+    fn integer_sqrt(n: N) -> N {
+        if n < N::one() + N::one() {
+            return n;
+        }
+
+        let two = N::one() + N::one();
+        let mut x = n;
+        let mut y = (x + N::one()) / two;
+
+        while y < x {
+            x = y;
+            y = (x + n / x) / two;
+        }
+
+        return x;
+    }
+
+    fn is_rational_prime(n: N) -> bool {
+        let two = N::one() + N::one();
+        let three = two + N::one();
+
+        if n < two {
+            return false;
+        }
+        if n <= three {
+            return true;
+        }
+        if (n % two).is_zero() {
+            return false;
+        }
+
+        let mut divisor = three;
+
+        while divisor <= n / divisor {
+            if (n % divisor).is_zero() {
+                return false;
+            }
+
+            divisor = divisor + two;
+        }
+
+        return true;
+    }
+
+    // Prime factorization of n > 0 by trial division, in increasing order of the primes.
+    fn rational_factors(mut n: N) -> Vec<(N, u32)> {
+        let two = N::one() + N::one();
+        let mut factors = Vec::new();
+        let mut divisor = two;
+
+        while divisor <= n / divisor {
+            let mut exponent = 0;
+
+            while (n % divisor).is_zero() {
+                n = n / divisor;
+                exponent += 1;
+            }
+            if exponent > 0 {
+                factors.push((divisor, exponent));
+            }
+
+            divisor = if divisor == two { divisor + N::one() } else { divisor + two };
+        }
+        if n > N::one() {
+            factors.push((n, 1));
+        }
+
+        return factors;
+    }
+
+    // The Gaussian prime a + bi with a > b > 0 lying over a rational prime p = 1 (mod 4).
+    fn split_prime(p: N) -> Self {
+        let mut b = N::one();
+
+        loop {
+            let square = p - b * b;
+            let a = Self::integer_sqrt(square);
+
+            if a * a == square {
+                return Self::new(a.max(b), a.min(b));
+            }
+
+            b = b + N::one();
+        }
+    }
+
+    // The unit u such that u * self is in the first quadrant.
+    fn normalizing_unit(&self) -> Self {
+        let mut unit = Self::one();
+        let mut value = *self;
+
+        if value.is_zero() {
+            return unit;
+        }
+
+        while !(value.real > N::zero() && value.imaginary >= N::zero()) {
+            value = value.mul_i();
+            unit = unit.mul_i();
+        }
+
+        return unit;
+    }
+
+    fn divides(&self, other: &Self) -> bool {
+        return Self::rem(other, self).is_zero();
+    }
+
+    /// Whether `self` is a Gaussian prime: either its norm is a rational prime, or it is
+    /// an associate of a rational prime congruent to 3 modulo 4. The norm of `self` must
+    /// fit in `N`.
+    pub fn is_prime(&self) -> bool {
+        let four = N::one() + N::one() + N::one() + N::one();
+        let three = four - N::one();
+
+        if self.real.is_zero() || self.imaginary.is_zero() {
+            let n = self.real.abs() + self.imaginary.abs();
+
+            return n % four == three && Self::is_rational_prime(n);
+        }
+
+        return Self::is_rational_prime(self.norm());
+    }
+
+    /// Factorization into normalized Gaussian primes with their multiplicities, in
+    /// increasing order of norm, together with the unit `u` such that `self` equals
+    /// `u` times the product of the factors. Zero has no factors and a zero unit. The norm
+    /// of `self` must fit in `N`.
+    pub fn factor(&self) -> (Self, Vec<(Self, u32)>) {
+        if self.is_zero() {
+            return (Self::zero(), Vec::new());
+        }
+
+        let two = N::one() + N::one();
+        let four = two + two;
+        let mut remaining = *self;
+        let mut factors = Vec::new();
+
+        for (p, _) in Self::rational_factors(self.norm()) {
+            let primes = if p == two {
+                vec![Self::new(N::one(), N::one())]
+            } else if p % four == N::one() {
+                let prime = Self::split_prime(p);
+
+                vec![prime, prime.conj().normalize()]
+            } else {
+                vec![Self::from_real(p)]
+            };
+
+            for prime in primes {
+                let mut exponent = 0;
+
+                while prime.divides(&remaining) {
+                    remaining = Self::div(&remaining, &prime);
+                    exponent += 1;
+                }
+                if exponent > 0 {
+                    factors.push((prime, exponent));
+                }
+            }
+        }
+
+        return (remaining, factors);
+    }
+
+    /// Returns `(g, s, t)` with `g = gcd(self, other)` normalized to the first quadrant
+    /// and `s * self + t * other = g`. The Bezout coefficients are no larger than the
+    /// operands, so this works whenever `div_rem` does.
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut old_r, mut r) = (*self, *other);
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+
+        while !r.is_zero() {
+            let (quotient, remainder) = Self::div_rem(&old_r, &r);
+
+            (old_r, r) = (r, remainder);
+            (old_s, s) = (s, Self::sub(&old_s, &Self::mul(&quotient, &s)));
+            (old_t, t) = (t, Self::sub(&old_t, &Self::mul(&quotient, &t)));
+        }
+
+        let unit = old_r.normalizing_unit();
+
+        return (Self::mul(&old_r, &unit), Self::mul(&old_s, &unit), Self::mul(&old_t, &unit));
+    }
+
+    /// Smallest-norm representative of `self` modulo `modulus`.
+    pub fn modulo(&self, modulus: &Self) -> Self {
+        return Self::rem(self, modulus);
+    }
+
+    pub fn mod_add(&self, other: &Self, modulus: &Self) -> Self {
+        return Self::add(&self.modulo(modulus), &other.modulo(modulus)).modulo(modulus);
+    }
+
+    pub fn mod_sub(&self, other: &Self, modulus: &Self) -> Self {
+        return Self::sub(&self.modulo(modulus), &other.modulo(modulus)).modulo(modulus);
+    }
+
+    /// The norm of `modulus` must fit in `N`, as the product of two reduced values can
+    /// be as large as it.
+    pub fn mod_mul(&self, other: &Self, modulus: &Self) -> Self {
+        return Self::mul(&self.modulo(modulus), &other.modulo(modulus)).modulo(modulus);
+    }
+
+    /// The norm of `modulus` must fit in `N`, as for `mod_mul`.
+    pub fn mod_pow(&self, exponent: usize, modulus: &Self) -> Self {
+        let mut n = Self::one().modulo(modulus);
+        let mut base = self.modulo(modulus);
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                n = n.mod_mul(&base, modulus);
+            }
+
+            exponent /= 2;
+
+            if exponent > 0 {
+                base = base.mod_mul(&base, modulus);
+            }
+        }
+
+        return n;
+    }
+
+    /// Multiplicative inverse modulo `modulus`, if `self` and `modulus` are coprime.
+    pub fn mod_inv(&self, modulus: &Self) -> Option<Self> {
+        let (gcd, s, _) = Self::extended_gcd(self, modulus);
+
+        if !gcd.is_one() {
+            return None;
+        }
+
+        return Some(s.modulo(modulus));
+    }
+
+    /// Writes `n` as `a^2 + b^2` with `a >= b >= 0`, if possible.
+    pub fn sum_of_two_squares(n: N) -> Option<(N, N)> {
+        if n < N::zero() {
+            return None;
+        }
+        if n.is_zero() {
+            return Some((N::zero(), N::zero()));
+        }
+
+        let two = N::one() + N::one();
+        let four = two + two;
+        let mut value = Self::one();
+
+        for (p, exponent) in Self::rational_factors(n) {
+            let (factor, exponent) = if p == two {
+                (Self::new(N::one(), N::one()), exponent)
+            } else if p % four == N::one() {
+                (Self::split_prime(p), exponent)
+            } else if exponent % 2 == 0 {
+                (Self::from_real(p), exponent / 2)
+            } else {
+                return None;
+            };
+
+            for _ in 0..exponent {
+                value = Self::mul(&value, &factor);
+            }
+        }
+
+        let (a, b) = (value.real.abs(), value.imaginary.abs());
+
+        return Some((a.max(b), a.min(b)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type G = GaussianInteger<i32>;
+
+    #[test]
+    fn primes() {
+        assert!(G::new(1, 1).is_prime());
+        assert!(G::new(3, 0).is_prime());
+        assert!(G::new(0, -7).is_prime());
+        assert!(G::new(2, 1).is_prime());
+        assert!(!G::new(5, 0).is_prime());
+        assert!(!G::new(2, 0).is_prime());
+
+        // Norm 46340^2 + 1 is just below i32::MAX.
+        let large = G::new(46340, 1);
+        let (_, factors) = large.factor();
+        assert_eq!(large.is_prime(), factors.len() == 1 && factors[0].1 == 1);
+    }
+
+    #[test]
+    fn factor() {
+        for value in [G::new(12, 0), G::new(-7, 24), G::new(0, 65), G::new(46340, 1), G::new(-1, 0)] {
+            let (unit, factors) = value.factor();
+            let mut product = unit;
+
+            for (prime, exponent) in &factors {
+                assert!(prime.is_prime());
+                assert_eq!(*prime, prime.normalize());
+
+                for _ in 0..*exponent {
+                    product = G::mul(&product, prime);
+                }
+            }
+
+            assert!(unit.is_unit());
+            assert_eq!(product, value);
+        }
+    }
+
+    #[test]
+    fn extended_gcd() {
+        for (a, b) in [(G::new(60000, 0), G::new(40000, 0)), (G::new(32000, -27000), G::new(-15000, 31000)), (G::new(3, 4), G::new(0, 0))] {
+            let (g, s, t) = a.extended_gcd(&b);
+
+            assert_eq!(g, a.gcd(&b));
+            assert_eq!(G::add(&G::mul(&s, &a), &G::mul(&t, &b)), g);
+        }
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        let modulus = G::new(30000, 20001);
+        let (a, b) = (G::new(123456, -98765), G::new(-55555, 77777));
+
+        assert_eq!(a.mod_add(&b, &modulus), G::add(&a, &b).modulo(&modulus));
+        assert_eq!(a.mod_sub(&b, &modulus), G::sub(&a, &b).modulo(&modulus));
+
+        let inverse = a.mod_inv(&modulus).unwrap();
+        assert_eq!(a.mod_mul(&inverse, &modulus), G::one());
+        assert_eq!(a.mod_pow(0, &modulus), G::one());
+        assert_eq!(a.mod_pow(3, &modulus), a.mod_mul(&a, &modulus).mod_mul(&a, &modulus));
+        assert_eq!(G::new(2, 0).mod_inv(&G::new(4, 0)), None);
+    }
+
+    #[test]
+    fn sum_of_two_squares() {
+        assert_eq!(G::sum_of_two_squares(0), Some((0, 0)));
+        assert_eq!(G::sum_of_two_squares(25), Some((4, 3)));
+        assert_eq!(G::sum_of_two_squares(21), None);
+        assert_eq!(G::sum_of_two_squares(-1), None);
+
+        let (a, b) = G::sum_of_two_squares(2147395600).unwrap();
+        assert_eq!(a as i64 * a as i64 + b as i64 * b as i64, 2147395600);
+    }
+}
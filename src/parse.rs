@@ -0,0 +1,409 @@
+use super::Complex;
+
+use core::fmt::{self, Display};
+use std::str::FromStr;
+
+use num_traits::Float;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseComplexErrorKind {
+    Empty,
+    UnexpectedEnd,
+    UnsupportedRadix,
+    InvalidNumber,
+    ExpectedNumber,
+    UnexpectedCharacter,
+    DuplicatePart
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseComplexError {
+    kind: ParseComplexErrorKind,
+    position: usize
+}
+
+impl ParseComplexError {
+    pub fn kind(&self) -> ParseComplexErrorKind {
+        return self.kind;
+    }
+
+    /// Byte offset in the input at which parsing failed.
+    pub fn position(&self) -> usize {
+        return self.position;
+    }
+}
+
+impl Display for ParseComplexError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self.kind {
+            ParseComplexErrorKind::Empty => "cannot parse complex number from empty string",
+            ParseComplexErrorKind::UnexpectedEnd => "unexpected end of input",
+            ParseComplexErrorKind::UnsupportedRadix => "radix must be between 2 and 18",
+            ParseComplexErrorKind::InvalidNumber => "invalid number",
+            ParseComplexErrorKind::ExpectedNumber => "expected a number",
+            ParseComplexErrorKind::UnexpectedCharacter => "unexpected character",
+            ParseComplexErrorKind::DuplicatePart => "real or imaginary part given twice"
+        };
+
+        return write!(formatter, "{description} at byte {}", self.position);
+    }
+}
+
+impl std::error::Error for ParseComplexError {}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    radix: u32
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str, radix: u32) -> Self {
+        return Self {
+            input: input,
+            position: 0,
+            radix: radix
+        };
+    }
+
+    fn error(&self, kind: ParseComplexErrorKind) -> ParseComplexError {
+        return ParseComplexError {
+            kind: kind,
+            position: self.position
+        };
+    }
+
+    fn rest(&self) -> &'a str {
+        return &self.input[self.position..];
+    }
+
+    fn peek(&self) -> Option<char> {
+        return self.rest().chars().next();
+    }
+
+    fn is_at_end(&self) -> bool {
+        return self.position == self.input.len();
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() && c.is_whitespace() {
+            self.position += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            return true;
+        }
+
+        return false;
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseComplexError> {
+        if self.eat(expected) {
+            return Ok(());
+        }
+        if self.is_at_end() {
+            return Err(self.error(ParseComplexErrorKind::UnexpectedEnd));
+        }
+
+        return Err(self.error(ParseComplexErrorKind::UnexpectedCharacter));
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        if let Some(prefix) = self.rest().get(..word.len()) && prefix.eq_ignore_ascii_case(word) {
+            self.position += word.len();
+            return true;
+        }
+
+        return false;
+    }
+
+    // Returns whether a minus sign was consumed, or None if there is no sign.
+    fn sign(&mut self) -> Option<bool> {
+        if self.eat('+') {
+            return Some(false);
+        }
+        if self.eat('-') {
+            return Some(true);
+        }
+
+        return None;
+    }
+
+    fn eat_digits(&mut self) -> usize {
+        let start = self.position;
+
+        while let Some(c) = self.peek() && c.is_digit(self.radix) {
+            self.position += 1;
+        }
+
+        return self.position - start;
+    }
+
+    // Unsigned number, or None if no number starts here.
+    fn number<N: Float>(&mut self) -> Result<Option<N>, ParseComplexError> {
+        let start = self.position;
+
+        if self.eat_word("infinity") || self.eat_word("inf") {
+            return Ok(Some(N::infinity()));
+        }
+        if self.eat_word("nan") {
+            return Ok(Some(N::nan()));
+        }
+
+        let mut digits = self.eat_digits();
+
+        if self.eat('.') {
+            digits += self.eat_digits();
+        }
+        if self.position == start {
+            return Ok(None);
+        }
+        if digits == 0 {
+            self.position = start;
+            return Err(self.error(ParseComplexErrorKind::InvalidNumber));
+        }
+
+        if self.radix == 10 && matches!(self.peek(), Some('e' | 'E')) {
+            let mantissa_end = self.position;
+
+            self.position += 1;
+            let _ = self.sign();
+
+            if self.eat_digits() == 0 {
+                self.position = mantissa_end;
+            }
+        }
+
+        return match N::from_str_radix(&self.input[start..self.position], self.radix) {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => {
+                self.position = start;
+                Err(self.error(ParseComplexErrorKind::InvalidNumber))
+            }
+        };
+    }
+
+    fn imaginary_unit(&mut self) -> bool {
+        return self.eat('i') || self.eat('j');
+    }
+
+    // Signed real number with optional whitespace before and after the sign.
+    fn real<N: Float>(&mut self) -> Result<N, ParseComplexError> {
+        self.skip_whitespace();
+
+        let negative = self.sign().unwrap_or(false);
+        self.skip_whitespace();
+
+        return match self.number::<N>()? {
+            Some(value) => Ok(if negative { -value } else { value }),
+            None if self.is_at_end() => Err(self.error(ParseComplexErrorKind::UnexpectedEnd)),
+            None => Err(self.error(ParseComplexErrorKind::ExpectedNumber))
+        };
+    }
+
+    // `(real, imaginary)`, with the opening parenthesis already consumed.
+    fn pair<N: Float>(&mut self) -> Result<Complex<N>, ParseComplexError> {
+        let real = self.real()?;
+        self.skip_whitespace();
+        self.expect(',')?;
+
+        let imaginary = self.real()?;
+        self.skip_whitespace();
+        self.expect(')')?;
+
+        return Ok(Complex::new(real, imaginary));
+    }
+
+    // Angle after `∠` or `@`, in radians unless followed by `°` or `deg`.
+    fn polar<N: Float>(&mut self, amplitude: N) -> Result<Complex<N>, ParseComplexError> {
+        let mut argument = self.real::<N>()?;
+
+        if self.eat('°') || self.eat_word("deg") {
+            argument = argument.to_radians();
+        } else {
+            let _ = self.eat_word("rad");
+        }
+
+        return Ok(Complex::from_argument_amplitude(argument, amplitude));
+    }
+
+    // A real or imaginary term without its sign. Returns the value and whether it is imaginary.
+    fn term<N: Float>(&mut self) -> Result<(N, bool), ParseComplexError> {
+        let number = self.number()?;
+        let imaginary = self.imaginary_unit();
+
+        return match (number, imaginary) {
+            (None, false) => Err(self.error(ParseComplexErrorKind::ExpectedNumber)),
+            (number, imaginary) => Ok((number.unwrap_or_else(N::one), imaginary))
+        };
+    }
+
+    fn signed_term<N: Float>(&mut self, negative: bool) -> Result<(N, bool, usize), ParseComplexError> {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let (value, imaginary) = self.term::<N>()?;
+
+        return Ok((if negative { -value } else { value }, imaginary, start));
+    }
+
+    fn cartesian<N: Float>(&mut self) -> Result<Complex<N>, ParseComplexError> {
+        let negative = self.sign().unwrap_or(false);
+        let (value, imaginary, _) = self.signed_term::<N>(negative)?;
+        let mut result = Complex::zero();
+
+        result[imaginary] = value;
+
+        self.skip_whitespace();
+
+        if !imaginary && (self.eat('∠') || self.eat('@')) {
+            return self.polar(value);
+        }
+
+        if let Some(negative) = self.sign() {
+            let (value, second_imaginary, start) = self.signed_term::<N>(negative)?;
+
+            if second_imaginary == imaginary {
+                // Report a stray character such as the `x` in `3+4x` rather than the duplicate.
+                if let Some(c) = self.peek() && !c.is_whitespace() {
+                    return Err(self.error(ParseComplexErrorKind::UnexpectedCharacter));
+                }
+
+                return Err(ParseComplexError {
+                    kind: ParseComplexErrorKind::DuplicatePart,
+                    position: start
+                });
+            }
+
+            result[second_imaginary] = value;
+        }
+
+        return Ok(result);
+    }
+
+    fn value<N: Float>(&mut self) -> Result<Complex<N>, ParseComplexError> {
+        if self.eat('(') {
+            return self.pair();
+        }
+
+        return self.cartesian();
+    }
+
+    fn finish(&mut self) -> Result<(), ParseComplexError> {
+        self.skip_whitespace();
+
+        if !self.is_at_end() {
+            return Err(self.error(ParseComplexErrorKind::UnexpectedCharacter));
+        }
+
+        return Ok(());
+    }
+}
+
+/// Parses `a+bi`, `a`, `bi`, `i` (or `j` for the imaginary unit), `(a,b)`, or polar
+/// `r∠θ` / `r@θ` with θ in radians or followed by `°`, `deg` or `rad`. Numbers are written
+/// in `radix`, which must not exceed 18 so that `i` is never a digit.
+///
+/// Cartesian output of `Display`, `LowerExp`, `UpperExp` and `display_j` parses back
+/// exactly; the polar form only to within the rounding of `cos` and `sin`.
+pub(crate) fn from_str_radix<N: Float>(input: &str, radix: u32) -> Result<Complex<N>, ParseComplexError> {
+    let mut parser = Parser::new(input, radix);
+
+    if !(2..=18).contains(&radix) {
+        return Err(parser.error(ParseComplexErrorKind::UnsupportedRadix));
+    }
+
+    parser.skip_whitespace();
+
+    if parser.is_at_end() {
+        return Err(parser.error(ParseComplexErrorKind::Empty));
+    }
+
+    let value = parser.value()?;
+    parser.finish()?;
+
+    return Ok(value);
+}
+
+impl<N: Float> FromStr for Complex<N> {
+    type Err = ParseComplexError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return from_str_radix(input, 10);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type C = Complex<f64>;
+
+    fn assert_identical(actual: C, expected: C) {
+        assert_eq!(actual.real.to_bits(), expected.real.to_bits(), "{actual:?} != {expected:?}");
+        assert_eq!(actual.imaginary.to_bits(), expected.imaginary.to_bits(), "{actual:?} != {expected:?}");
+    }
+
+    #[test]
+    fn round_trip() {
+        let parts = [0.0, -0.0, 1.0, -2.5, 0.1, 1.0 / 3.0, f64::INFINITY, f64::NEG_INFINITY, f64::from_bits(1), -f64::MIN_POSITIVE / 3.0, 1e300, -1e-300, f64::MAX];
+
+        for real in parts {
+            for imaginary in parts {
+                let value = C::new(real, imaginary);
+
+                for text in [format!("{value}"), format!("{value:e}"), format!("{value:E}"), format!("{}", value.display_j()), format!("{:+e}", value.display_j())] {
+                    assert_identical(text.parse().unwrap(), value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_f32() {
+        for value in [Complex::new(0.1f32, -0.0), Complex::new(f32::from_bits(1), f32::MAX), Complex::new(-f32::INFINITY, 3.5)] {
+            assert_eq!(format!("{value}").parse::<Complex<f32>>().unwrap().to_array().map(f32::to_bits), value.to_array().map(f32::to_bits));
+        }
+    }
+
+    #[test]
+    fn polar_form_is_approximate() {
+        let value: C = format!("{:#}", C::new(3.0, 4.0)).parse().unwrap();
+
+        assert_eq!(value, C::new(3.0000000000000004, 3.9999999999999996));
+    }
+
+    #[test]
+    fn notations() {
+        assert_identical("3+4i".parse().unwrap(), C::new(3.0, 4.0));
+        assert_identical("-2.5e3-1j".parse().unwrap(), C::new(-2500.0, -1.0));
+        assert_identical("i".parse().unwrap(), C::new(0.0, 1.0));
+        assert_identical("-j".parse().unwrap(), C::new(0.0, -1.0));
+        assert_identical(" ( 1 , -2 ) ".parse().unwrap(), C::new(1.0, -2.0));
+        assert_identical("2∠90°".parse().unwrap(), C::from_argument_amplitude(90f64.to_radians(), 2.0));
+        assert_identical("1.5@0.52rad".parse().unwrap(), C::from_argument_amplitude(0.52, 1.5));
+        assert_identical("2@0".parse().unwrap(), C::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn error_positions() {
+        let error = |input: &str| {
+            let error = input.parse::<C>().unwrap_err();
+            (error.kind(), error.position())
+        };
+
+        assert_eq!(error(""), (ParseComplexErrorKind::Empty, 0));
+        assert_eq!(error("3+4x"), (ParseComplexErrorKind::UnexpectedCharacter, 3));
+        assert_eq!(error("3+4i+5"), (ParseComplexErrorKind::UnexpectedCharacter, 4));
+        assert_eq!(error("3i+4j"), (ParseComplexErrorKind::DuplicatePart, 3));
+        assert_eq!(error("(1;2)"), (ParseComplexErrorKind::UnexpectedCharacter, 2));
+        assert_eq!(error("(1,2"), (ParseComplexErrorKind::UnexpectedEnd, 4));
+        assert_eq!(error("1∠"), (ParseComplexErrorKind::UnexpectedEnd, 4));
+        assert_eq!(error("1@i"), (ParseComplexErrorKind::ExpectedNumber, 2));
+        assert_eq!(error("3+."), (ParseComplexErrorKind::InvalidNumber, 2));
+    }
+}
@@ -0,0 +1,367 @@
+use super::Complex;
+
+use std::ops::{Div, DivAssign, Mul, MulAssign, Neg};
+
+use num_traits::{Float, FloatConst};
+
+
+/// Complex number stored as amplitude and argument, so that products, quotients and
+/// powers don't round-trip through cartesian form.
+///
+/// Converting to and from `Complex` rounds, so a value converted back and forth can differ
+/// in the last bits, e.g. `3 + 4i` comes back as `3.0000000000000004 + 3.9999999999999996i`.
+///
+/// Results of the arithmetic methods are normalized: the amplitude is non-negative and
+/// the argument lies in (-pi, pi].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Polar<N: Float> {
+    pub amplitude: N,
+    pub argument: N
+}
+
+impl<N: Float+FloatConst> Polar<N> {
+    pub const fn new(amplitude: N, argument: N) -> Self {
+        return Self {
+            amplitude: amplitude,
+            argument: argument
+        };
+    }
+
+    pub fn one() -> Self {
+        return Self {
+            amplitude: N::one(),
+            argument: N::zero()
+        };
+    }
+
+    pub fn from_complex(value: &Complex<N>) -> Self {
+        return Self {
+            amplitude: value.amplitude(),
+            argument: value.argument()
+        };
+    }
+
+    pub fn to_complex(&self) -> Complex<N> {
+        return Complex::from_argument_amplitude(self.argument, self.amplitude);
+    }
+
+    fn normalize_argument(argument: N) -> N {
+        let tau = N::TAU();
+        let mut argument = argument % tau;
+
+        if argument > N::PI() {
+            argument = argument - tau;
+        } else if argument <= -N::PI() {
+            argument = argument + tau;
+        }
+
+        return argument;
+    }
+
+    /// Makes the amplitude non-negative and brings the argument into (-pi, pi].
+    pub fn normalize(&self) -> Self {
+        let argument = if self.amplitude.is_sign_negative() { self.argument + N::PI() } else { self.argument };
+
+        return Self {
+            amplitude: self.amplitude.abs(),
+            argument: Self::normalize_argument(argument)
+        };
+    }
+
+    pub fn normalize_assign(&mut self) {
+        *self = Self::normalize(self);
+    }
+
+    pub fn conj(&self) -> Self {
+        return Self::new(self.amplitude, -self.argument).normalize();
+    }
+
+    pub fn conj_assign(&mut self) {
+        *self = Self::conj(self);
+    }
+
+    pub fn neg(&self) -> Self {
+        return Self::new(self.amplitude, self.argument + N::PI()).normalize();
+    }
+
+    pub fn neg_assign(&mut self) {
+        *self = Self::neg(self);
+    }
+
+    pub fn recip(&self) -> Self {
+        return Self::new(self.amplitude.recip(), -self.argument).normalize();
+    }
+
+    pub fn recip_assign(&mut self) {
+        *self = Self::recip(self);
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        return Self::new(self.amplitude * other.amplitude, self.argument + other.argument).normalize();
+    }
+
+    pub fn mul_assign(&mut self, other: &Self) {
+        *self = Self::mul(self, other);
+    }
+
+    pub fn div(&self, other: &Self) -> Self {
+        return Self::new(self.amplitude / other.amplitude, self.argument - other.argument).normalize();
+    }
+
+    pub fn div_assign(&mut self, other: &Self) {
+        *self = Self::div(self, other);
+    }
+
+    pub fn powi(&self, exponent: isize) -> Self {
+        let amplitude = match i32::try_from(exponent) {
+            Ok(exponent) => self.amplitude.powi(exponent),
+            Err(_) => self.amplitude.powf(N::from(exponent).unwrap())
+        };
+        let argument = Self::normalize_argument(self.argument) * N::from(exponent).unwrap();
+
+        return Self::new(amplitude, argument).normalize();
+    }
+
+    pub fn powi_assign(&mut self, exponent: isize) {
+        *self = Self::powi(self, exponent);
+    }
+
+    /// Principal value, taking the argument in (-pi, pi] before scaling it.
+    pub fn powf(&self, exponent: N) -> Self {
+        let value = Self::normalize(self);
+
+        return Self::new(value.amplitude.powf(exponent), value.argument * exponent).normalize();
+    }
+
+    pub fn powf_assign(&mut self, exponent: N) {
+        *self = Self::powf(self, exponent);
+    }
+
+    /// Principal square root, with argument in (-pi/2, pi/2].
+    pub fn sqrt(&self) -> Self {
+        let value = Self::normalize(self);
+        let two = N::one() + N::one();
+
+        return Self::new(value.amplitude.sqrt(), value.argument / two);
+    }
+
+    pub fn sqrt_assign(&mut self) {
+        *self = Self::sqrt(self);
+    }
+}
+
+impl<N: Float+FloatConst> Default for Polar<N> {
+    fn default() -> Self {
+        return Self::one();
+    }
+}
+
+impl<N: Float+FloatConst> From<Complex<N>> for Polar<N> {
+    fn from(value: Complex<N>) -> Self {
+        return Self::from_complex(&value);
+    }
+}
+
+impl<N: Float+FloatConst> From<Polar<N>> for Complex<N> {
+    fn from(value: Polar<N>) -> Self {
+        return value.to_complex();
+    }
+}
+
+impl<N: Float+FloatConst> Div for Polar<N> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        return Self::div(&self, &other);
+    }
+}
+
+impl<N: Float+FloatConst> Div<Complex<N>> for Polar<N> {
+    type Output = Self;
+
+    fn div(self, other: Complex<N>) -> Self::Output {
+        return Self::div(&self, &Self::from_complex(&other));
+    }
+}
+
+impl<N: Float+FloatConst> Div<Polar<N>> for Complex<N> {
+    type Output = Self;
+
+    fn div(self, other: Polar<N>) -> Self::Output {
+        return Polar::div(&Polar::from_complex(&self), &other).to_complex();
+    }
+}
+
+impl<N: Float+FloatConst> DivAssign for Polar<N> {
+    fn div_assign(&mut self, other: Self) {
+        Self::div_assign(self, &other);
+    }
+}
+
+impl<N: Float+FloatConst> DivAssign<Complex<N>> for Polar<N> {
+    fn div_assign(&mut self, other: Complex<N>) {
+        Self::div_assign(self, &Self::from_complex(&other));
+    }
+}
+
+impl<N: Float+FloatConst> DivAssign<Polar<N>> for Complex<N> {
+    fn div_assign(&mut self, other: Polar<N>) {
+        *self = *self / other;
+    }
+}
+
+impl<N: Float+FloatConst> Mul for Polar<N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        return Self::mul(&self, &other);
+    }
+}
+
+impl<N: Float+FloatConst> Mul<Complex<N>> for Polar<N> {
+    type Output = Self;
+
+    fn mul(self, other: Complex<N>) -> Self::Output {
+        return Self::mul(&self, &Self::from_complex(&other));
+    }
+}
+
+impl<N: Float+FloatConst> Mul<Polar<N>> for Complex<N> {
+    type Output = Self;
+
+    fn mul(self, other: Polar<N>) -> Self::Output {
+        return Polar::mul(&Polar::from_complex(&self), &other).to_complex();
+    }
+}
+
+impl<N: Float+FloatConst> MulAssign for Polar<N> {
+    fn mul_assign(&mut self, other: Self) {
+        Self::mul_assign(self, &other);
+    }
+}
+
+impl<N: Float+FloatConst> MulAssign<Complex<N>> for Polar<N> {
+    fn mul_assign(&mut self, other: Complex<N>) {
+        Self::mul_assign(self, &Self::from_complex(&other));
+    }
+}
+
+impl<N: Float+FloatConst> MulAssign<Polar<N>> for Complex<N> {
+    fn mul_assign(&mut self, other: Polar<N>) {
+        *self = *self * other;
+    }
+}
+
+impl<N: Float+FloatConst> Neg for Polar<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        return Self::neg(&self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    type P = Polar<f64>;
+
+    fn assert_close(actual: P, expected: P) {
+        assert!((actual.amplitude - expected.amplitude).abs() <= 1e-15 * expected.amplitude, "{actual:?} != {expected:?}");
+        assert!((actual.argument - expected.argument).abs() <= 1e-15 * PI, "{actual:?} != {expected:?}");
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(P::new(2.0, 0.5).normalize(), P::new(2.0, 0.5));
+        assert_eq!(P::new(2.0, PI).normalize(), P::new(2.0, PI));
+        assert_eq!(P::new(2.0, -PI).normalize(), P::new(2.0, PI));
+        assert_close(P::new(2.0, 3.0 * FRAC_PI_2).normalize(), P::new(2.0, -FRAC_PI_2));
+        assert_close(P::new(1.0, 7.0 * PI).normalize(), P::new(1.0, PI));
+        assert_close(P::new(1.0, -5.0 * FRAC_PI_2).normalize(), P::new(1.0, -FRAC_PI_2));
+
+        // A negative amplitude turns the argument by pi.
+        assert_eq!(P::new(-2.0, 0.0).normalize(), P::new(2.0, PI));
+        assert_close(P::new(-2.0, FRAC_PI_2).normalize(), P::new(2.0, -FRAC_PI_2));
+        assert_close(P::new(-2.0, -FRAC_PI_2).normalize(), P::new(2.0, FRAC_PI_2));
+
+        let mut value = P::new(-3.0, PI);
+        value.normalize_assign();
+        assert_eq!(value, P::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (P::new(2.0, 3.0), P::new(3.0, 1.0));
+
+        // Arguments wrap back into (-pi, pi].
+        assert_close(a * b, P::new(6.0, 4.0 - 2.0 * PI));
+        assert_close(P::div(&P::new(6.0, -3.0), &b), P::new(2.0, 2.0 * PI - 4.0));
+        assert_eq!(P::new(2.0, 0.5) / P::new(4.0, 0.25), P::new(0.5, 0.25));
+        assert_eq!(P::new(2.0, 0.5).conj(), P::new(2.0, -0.5));
+        assert_eq!(P::new(2.0, PI).conj(), P::new(2.0, PI));
+        assert_close(-P::new(2.0, 0.5), P::new(2.0, 0.5 - PI));
+        assert_eq!(P::new(4.0, 0.5).recip(), P::new(0.25, -0.5));
+
+        let mut c = a;
+        c *= b;
+        c /= b;
+        assert_close(c, a);
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!(P::new(2.0, 1.0).powi(3), P::new(8.0, 3.0));
+        assert_close(P::new(2.0, 2.0).powi(2), P::new(4.0, 4.0 - 2.0 * PI));
+        assert_eq!(P::new(2.0, 1.0).powi(-2), P::new(0.25, -2.0));
+        assert_eq!(P::new(-2.0, 0.0).powi(3), P::new(8.0, PI));
+        assert_eq!(P::new(-2.0, 0.0).powi(2), P::new(4.0, 0.0));
+        assert_eq!(P::new(1.0, 0.0).powi(isize::MIN), P::new(1.0, 0.0));
+        assert_eq!(P::new(2.0, 0.0).powi(isize::MIN), P::new(0.0, 0.0));
+
+        // The argument is taken in (-pi, pi] first, so powf gives principal values.
+        assert_close(P::new(4.0, FRAC_PI_2).powf(0.5), P::new(2.0, FRAC_PI_4));
+        assert_close(P::new(4.0, 5.0 * FRAC_PI_2).powf(0.5), P::new(2.0, FRAC_PI_4));
+        assert_close(P::new(-4.0, 0.0).powf(0.5), P::new(2.0, FRAC_PI_2));
+        assert_close(P::new(-4.0, 0.0).sqrt(), P::new(2.0, FRAC_PI_2));
+        assert_close(P::new(9.0, -PI).sqrt(), P::new(3.0, FRAC_PI_2));
+    }
+
+    #[test]
+    fn conversions() {
+        let z = Complex::new(3.0, 4.0);
+        let polar = P::from(z);
+
+        assert_eq!(polar, P::new(5.0, 4f64.atan2(3.0)));
+        assert_eq!(Complex::from(polar), Complex::new(3.0000000000000004, 3.9999999999999996));
+        assert_eq!(P::from(Complex::new(-1.0, 0.0)), P::new(1.0, PI));
+        assert_eq!(P::from(Complex::new(-1.0, -0.0)), P::new(1.0, -PI));
+        assert_eq!(Complex::from(P::new(2.0, 0.0)), Complex::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn mixed_operands() {
+        let (z, p) = (Complex::new(0.0, 2.0), P::new(3.0, FRAC_PI_2));
+
+        assert_close(p * z, P::new(6.0, PI));
+        assert_close(p / z, P::new(1.5, 0.0));
+
+        let product = z * p;
+        assert!((product.real + 6.0).abs() < 1e-15 && product.imaginary.abs() < 1e-15);
+
+        let quotient = z / p;
+        assert!((quotient.real - 2.0 / 3.0).abs() < 1e-15 && quotient.imaginary.abs() < 1e-15);
+
+        let mut c = z;
+        c *= p;
+        c /= p;
+        assert!((c.real).abs() < 1e-15 && (c.imaginary - 2.0).abs() < 1e-15);
+
+        let mut q = p;
+        q *= z;
+        q /= z;
+        assert_close(q, p);
+    }
+}
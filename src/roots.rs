@@ -0,0 +1,122 @@
+use super::Complex;
+
+use std::iter::FusedIterator;
+
+use num_traits::{Float, FloatConst};
+
+
+#[derive(Clone, Debug)]
+pub struct Roots<N: Float> {
+    amplitude: N,
+    argument: N,
+    step: N,
+    index: usize,
+    count: usize
+}
+
+impl<N: Float+FloatConst> Roots<N> {
+    pub(crate) fn new(value: &Complex<N>, count: usize) -> Self {
+        let n = N::from(count).unwrap_or_else(N::infinity);
+
+        return Self {
+            amplitude: value.amplitude().powf(n.recip()),
+            argument: value.argument() / n,
+            step: N::TAU() / n,
+            index: 0,
+            count: count
+        };
+    }
+}
+
+impl<N: Float> Roots<N> {
+    fn root(&self, index: usize) -> Complex<N> {
+        let k = N::from(index).unwrap();
+
+        return Complex::from_argument_amplitude(self.argument + self.step * k, self.amplitude);
+    }
+}
+
+impl<N: Float> Iterator for Roots<N> {
+    type Item = Complex<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let root = self.root(self.index);
+        self.index += 1;
+
+        return Some(root);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+
+        return (remaining, Some(remaining));
+    }
+}
+
+impl<N: Float> DoubleEndedIterator for Roots<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        self.count -= 1;
+
+        return Some(self.root(self.count));
+    }
+}
+
+impl<N: Float> ExactSizeIterator for Roots<N> {}
+
+impl<N: Float> FusedIterator for Roots<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_roots(value: Complex<f64>, n: usize) {
+        let roots: Vec<_> = value.roots(n).collect();
+
+        assert_eq!(roots.len(), n);
+        assert!((roots[0].argument() - value.argument() / n as f64).abs() <= 1e-15);
+
+        for (i, root) in roots.iter().enumerate() {
+            let power = root.powu(n);
+            assert!(Complex::sub(&power, &value).amplitude() <= 1e-13 * value.amplitude(), "{root:?}^{n} = {power:?}");
+
+            for other in &roots[..i] {
+                assert!(Complex::sub(root, other).amplitude() > 1e-3 * root.amplitude(), "{root:?} repeats");
+            }
+        }
+    }
+
+    #[test]
+    fn roots() {
+        assert_roots(Complex::new(16.0, 0.0), 4);
+        assert_roots(Complex::new(-8.0, 0.0), 3);
+        assert_roots(Complex::new(3.0, -4.0), 7);
+        assert_roots(Complex::new(1e-300, 1e300), 12);
+        assert_roots(Complex::new(0.5, 0.25), 1);
+    }
+
+    #[test]
+    fn iterator() {
+        let z = Complex::new(1.0, 1.0);
+        let mut roots = z.roots(5);
+
+        assert_eq!(roots.len(), 5);
+        assert_eq!(roots.next_back(), z.roots(5).next_back());
+        assert_eq!(roots.len(), 4);
+        assert_eq!(roots.by_ref().count(), 4);
+        assert_eq!(roots.next(), None);
+        assert_eq!(z.roots(0).next(), None);
+
+        let forward: Vec<_> = z.roots(5).collect();
+        let mut backward: Vec<_> = z.roots(5).rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
+}
@@ -0,0 +1,193 @@
+use super::Complex;
+
+use std::iter::{Product, Sum};
+
+use num_traits::Float;
+
+
+// Running sum of one component with Neumaier's improved Kahan compensation.
+#[derive(Clone, Copy)]
+struct Compensated<N: Float> {
+    sum: N,
+    compensation: N
+}
+
+impl<N: Float> Compensated<N> {
+    fn new() -> Self {
+        return Self {
+            sum: N::zero(),
+            compensation: N::zero()
+        };
+    }
+
+    // Once the sum is non-finite the compensation would be inf - inf = NaN and poison every
+    // later term, so it is dropped; the sum alone then carries the correct inf or NaN.
+    fn kahan_add(&mut self, value: N) {
+        let y = value - self.compensation;
+        let t = self.sum + y;
+
+        self.compensation = if t.is_finite() { (t - self.sum) - y } else { N::zero() };
+        self.sum = t;
+    }
+
+    fn neumaier_add(&mut self, value: N) {
+        let t = self.sum + value;
+
+        if !t.is_finite() {
+            self.compensation = N::zero();
+        } else if self.sum.abs() >= value.abs() {
+            self.compensation = self.compensation + ((self.sum - t) + value);
+        } else {
+            self.compensation = self.compensation + ((value - t) + self.sum);
+        }
+
+        self.sum = t;
+    }
+
+    fn kahan_total(&self) -> N {
+        return self.sum;
+    }
+
+    fn neumaier_total(&self) -> N {
+        // A non-finite sum makes the compensation meaningless (inf - inf).
+        if !self.sum.is_finite() {
+            return self.sum;
+        }
+
+        return self.sum + self.compensation;
+    }
+}
+
+fn neumaier<N: Float, I: Iterator<Item = Complex<N>>>(values: I) -> Complex<N> {
+    let mut real = Compensated::new();
+    let mut imaginary = Compensated::new();
+
+    for value in values {
+        real.neumaier_add(value.real);
+        imaginary.neumaier_add(value.imaginary);
+    }
+
+    return Complex::new(real.neumaier_total(), imaginary.neumaier_total());
+}
+
+impl<N: Float> Complex<N> {
+    pub fn kahan_sum(values: &[Self]) -> Self {
+        let mut real = Compensated::new();
+        let mut imaginary = Compensated::new();
+
+        for value in values {
+            real.kahan_add(value.real);
+            imaginary.kahan_add(value.imaginary);
+        }
+
+        return Self::new(real.kahan_total(), imaginary.kahan_total());
+    }
+
+    pub fn neumaier_sum(values: &[Self]) -> Self {
+        return neumaier(values.iter().copied());
+    }
+
+    /// Error grows with the logarithm of the length instead of linearly, at the cost of no compensation.
+    pub fn pairwise_sum(values: &[Self]) -> Self {
+        const BLOCK: usize = 8;
+
+        if values.len() <= BLOCK {
+            let mut sum = Self::zero();
+
+            for value in values {
+                sum = Self::add(&sum, value);
+            }
+
+            return sum;
+        }
+
+        let (left, right) = values.split_at(values.len() / 2);
+
+        return Self::add(&Self::pairwise_sum(left), &Self::pairwise_sum(right));
+    }
+}
+
+/// Uses Neumaier compensated summation.
+impl<N: Float> Sum for Complex<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        return neumaier(iter);
+    }
+}
+
+impl<'a, N: Float> Sum<&'a Complex<N>> for Complex<N> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        return neumaier(iter.copied());
+    }
+}
+
+impl<N: Float> Product for Complex<N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut product = Self::one();
+
+        for value in iter {
+            product = Self::mul(&product, &value);
+        }
+
+        return product;
+    }
+}
+
+impl<'a, N: Float> Product<&'a Complex<N>> for Complex<N> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        return iter.copied().product();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type C = Complex<f64>;
+
+    fn ill_conditioned() -> Vec<C> {
+        let mut values = vec![C::new(1e16, -1e16)];
+
+        values.extend(std::iter::repeat_n(C::new(1.0, -1.0), 1000));
+        values.push(C::new(-1e16, 1e16));
+
+        return values;
+    }
+
+    #[test]
+    fn compensated_sums() {
+        let values = ill_conditioned();
+
+        assert_eq!(C::neumaier_sum(&values), C::new(1000.0, -1000.0));
+        assert_eq!(values.iter().sum::<C>(), C::new(1000.0, -1000.0));
+        assert_eq!(values.clone().into_iter().sum::<C>(), C::new(1000.0, -1000.0));
+        assert_eq!(C::kahan_sum(&[C::new(1.0, 0.0), C::new(1e100, 0.0), C::new(1.0, 0.0), C::new(-1e100, 0.0)]).real, 0.0);
+        assert_eq!(C::neumaier_sum(&[C::new(1.0, 0.0), C::new(1e100, 0.0), C::new(1.0, 0.0), C::new(-1e100, 0.0)]).real, 2.0);
+    }
+
+    #[test]
+    fn pairwise_sum() {
+        let values: Vec<C> = (0..1000).map(|k| C::new(k as f64, -0.5)).collect();
+
+        assert_eq!(C::pairwise_sum(&values), C::new(499500.0, -500.0));
+        assert_eq!(C::pairwise_sum(&[]), C::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn non_finite() {
+        let values = [C::new(f64::INFINITY, 0.0), C::new(1.0, 0.0)];
+
+        assert_eq!(C::kahan_sum(&values), C::new(f64::INFINITY, 0.0));
+        assert_eq!(C::neumaier_sum(&values), C::new(f64::INFINITY, 0.0));
+        assert_eq!(C::kahan_sum(&[C::new(1.0, 0.0), C::new(f64::INFINITY, 0.0), C::new(2.0, 0.0)]).real, f64::INFINITY);
+        assert!(C::kahan_sum(&[C::new(f64::INFINITY, 0.0), C::new(f64::NEG_INFINITY, 0.0)]).real.is_nan());
+        assert!(C::neumaier_sum(&[C::new(f64::NAN, 0.0), C::new(1.0, 0.0)]).real.is_nan());
+    }
+
+    #[test]
+    fn product() {
+        let values = [C::new(0.0, 1.0); 4];
+
+        assert_eq!(values.iter().product::<C>(), C::new(1.0, 0.0));
+        assert_eq!(values.into_iter().product::<C>(), C::new(1.0, 0.0));
+    }
+}
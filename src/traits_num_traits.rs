@@ -1,6 +1,7 @@
 use super::Complex;
+use super::parse::{self, ParseComplexError};
 
-use num_traits::{Bounded, AsPrimitive, FromPrimitive, NumCast, ToPrimitive, ConstOne, ConstZero, One, Zero, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, Float, FloatConst, Inv, Pow};
+use num_traits::{Bounded, AsPrimitive, FromPrimitive, NumCast, ToPrimitive, ConstOne, ConstZero, One, Zero, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, Float, FloatConst, Inv, MulAdd, MulAddAssign, Num, Pow};
 
 
 impl<N: Float> Inv for Complex<N> {
@@ -11,6 +12,20 @@ impl<N: Float> Inv for Complex<N> {
     }
 }
 
+impl<N: Float> MulAdd for Complex<N> {
+    type Output = Self;
+
+    fn mul_add(self, a: Self, b: Self) -> Self::Output {
+        return Self::mul_add(&self, &a, &b);
+    }
+}
+
+impl<N: Float> MulAddAssign for Complex<N> {
+    fn mul_add_assign(&mut self, a: Self, b: Self) {
+        Self::mul_add_assign(self, &a, &b);
+    }
+}
+
 impl<N: Float> Pow<N> for Complex<N> {
     type Output = Self;
 
@@ -37,6 +52,14 @@ impl<N: Float+Bounded> Bounded for Complex<N> {
     }
 }
 
+impl<N: Float> Num for Complex<N> {
+    type FromStrRadixErr = ParseComplexError;
+
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        return parse::from_str_radix(string, radix);
+    }
+}
+
 impl<N: Float> Zero for Complex<N> {
     fn zero() -> Self {
         return Self::zero();
@@ -220,18 +243,36 @@ impl<N: Float> From<(N, N)> for Complex<N> {
     }
 }
 
+impl<N: Float> From<[N; 2]> for Complex<N> {
+    fn from([real, imaginary]: [N; 2]) -> Self {
+        return Self::new(real, imaginary);
+    }
+}
+
 impl<N: Float> From<N> for Complex<N> {
     fn from(real: N) -> Self {
         return Self::from_real(real);
     }
 }
 
+impl From<Complex<f32>> for Complex<f64> {
+    fn from(value: Complex<f32>) -> Self {
+        return Self::new(value.real.into(), value.imaginary.into());
+    }
+}
+
 impl<N: Float> Into<(N, N)> for Complex<N> {
     fn into(self) -> (N, N) {
         return (self.real, self.imaginary);
     }
 }
 
+impl<N: Float> Into<[N; 2]> for Complex<N> {
+    fn into(self) -> [N; 2] {
+        return self.to_array();
+    }
+}
+
 impl<N: Float+AsPrimitive<i64>> AsPrimitive<i64> for Complex<N> {
     fn as_(self) -> i64 { self.real.as_() }
 }
@@ -473,3 +514,44 @@ impl<N: Float+CheckedNeg> CheckedNeg for Complex<N> {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ParseComplexErrorKind;
+
+    type C = Complex<f64>;
+
+    #[test]
+    fn from_f32() {
+        let z = Complex::new(0.1f32, -f32::MAX);
+
+        assert_eq!(<C as From<_>>::from(z), C::new(0.1f32 as f64, -(f32::MAX as f64)));
+
+        let widened: C = Complex::new(f32::INFINITY, -0.0f32).into();
+        assert!(widened.real == f64::INFINITY && widened.imaginary.is_sign_negative());
+    }
+
+    #[test]
+    fn from_str_radix() {
+        assert_eq!(C::from_str_radix("ff+1.8i", 16), Ok(C::new(255.0, 1.5)));
+        assert_eq!(C::from_str_radix("101-11i", 2), Ok(C::new(5.0, -3.0)));
+        assert_eq!(C::from_str_radix("-h.9j", 18), Ok(C::new(0.0, -17.5)));
+        assert_eq!(C::from_str_radix("(10, -20)", 8), Ok(C::new(8.0, -16.0)));
+        // In radix 16 `e` is a digit, not an exponent.
+        assert_eq!(C::from_str_radix("1e3", 16), Ok(C::new(483.0, 0.0)));
+        assert_eq!(C::from_str_radix("1e3", 10), Ok(C::new(1000.0, 0.0)));
+    }
+
+    #[test]
+    fn from_str_radix_errors() {
+        let kind = |input: &str, radix: u32| C::from_str_radix(input, radix).unwrap_err().kind();
+
+        assert_eq!(kind("12", 19), ParseComplexErrorKind::UnsupportedRadix);
+        assert_eq!(kind("12", 1), ParseComplexErrorKind::UnsupportedRadix);
+        assert_eq!(kind("102", 2), ParseComplexErrorKind::UnexpectedCharacter);
+        assert_eq!(kind("ff+gi", 16), ParseComplexErrorKind::ExpectedNumber);
+        assert_eq!(kind("   ", 16), ParseComplexErrorKind::Empty);
+        assert_eq!(kind("1+", 8), ParseComplexErrorKind::ExpectedNumber);
+    }
+}
@@ -1,6 +1,6 @@
-use super::Complex;
+use super::{Complex, Imaginary};
 
-use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign};
+use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Sub, SubAssign};
 
 use num_traits::Float;
 
@@ -76,22 +76,16 @@ impl<N: Float> BitXorAssign for Complex<N> {
 }
 
 impl<N: Float> Deref for Complex<N> {
-    type Target = (N, N);
+    type Target = [N; 2];
 
     fn deref(&self) -> &Self::Target {
-        let ptr = &(&self.real, &self.imaginary) as *const (&N, &N) as *const &(N, N);
-        let ptr: &(N, N) = unsafe { &*ptr };
-
-        return ptr;
+        return self.as_array();
     }
 }
 
 impl<N: Float> DerefMut for Complex<N> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        let ptr = &mut (&mut self.real, &mut self.imaginary) as *mut (&mut N, &mut N) as *mut &mut (N, N);
-        let ptr: &mut (N, N) = unsafe { &mut *ptr };
-
-        return ptr;
+        return self.as_array_mut();
     }
 }
 
@@ -181,6 +175,34 @@ impl<N: Float> Not for Complex<N> {
     }
 }
 
+impl<N: Float> Rem<N> for Complex<N> {
+    type Output = Self;
+
+    fn rem(self, other: N) -> Self::Output {
+        return Self::remf(&self, other);
+    }
+}
+
+impl<N: Float> Rem for Complex<N> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self::Output {
+        return Self::rem(&self, &other);
+    }
+}
+
+impl<N: Float> RemAssign<N> for Complex<N> {
+    fn rem_assign(&mut self, other: N) {
+        Self::remf_assign(self, other);
+    }
+}
+
+impl<N: Float> RemAssign for Complex<N> {
+    fn rem_assign(&mut self, other: Self) {
+        Self::rem_assign(self, &other);
+    }
+}
+
 impl<N: Float> Sub<N> for Complex<N> {
     type Output = Self;
 
@@ -209,3 +231,337 @@ impl<N: Float> SubAssign for Complex<N> {
     }
 }
 
+
+// Mixed-precision arithmetic: Complex<f32> operands are widened and the result is Complex<f64>.
+macro_rules! impl_mixed_precision_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $trait<Complex<f32>> for Complex<f64> {
+            type Output = Complex<f64>;
+
+            fn $method(self, other: Complex<f32>) -> Self::Output {
+                return $trait::$method(self, Complex::<f64>::from(other));
+            }
+        }
+
+        impl $trait<Complex<f64>> for Complex<f32> {
+            type Output = Complex<f64>;
+
+            fn $method(self, other: Complex<f64>) -> Self::Output {
+                return $trait::$method(Complex::<f64>::from(self), other);
+            }
+        }
+
+        impl $trait<f32> for Complex<f64> {
+            type Output = Complex<f64>;
+
+            fn $method(self, other: f32) -> Self::Output {
+                return $trait::$method(self, f64::from(other));
+            }
+        }
+
+        impl $assign_trait<Complex<f32>> for Complex<f64> {
+            fn $assign_method(&mut self, other: Complex<f32>) {
+                $assign_trait::$assign_method(self, Complex::<f64>::from(other));
+            }
+        }
+
+        impl $assign_trait<f32> for Complex<f64> {
+            fn $assign_method(&mut self, other: f32) {
+                $assign_trait::$assign_method(self, f64::from(other));
+            }
+        }
+    };
+}
+
+impl_mixed_precision_op!(Add, add, AddAssign, add_assign);
+impl_mixed_precision_op!(Div, div, DivAssign, div_assign);
+impl_mixed_precision_op!(Mul, mul, MulAssign, mul_assign);
+impl_mixed_precision_op!(Sub, sub, SubAssign, sub_assign);
+
+// Forwards every combination of owned and borrowed operands to the by-value impls.
+macro_rules! impl_reference_complex_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<N: Float> $trait<&Complex<N>> for Complex<N> {
+            type Output = Complex<N>;
+
+            fn $method(self, other: &Complex<N>) -> Self::Output {
+                return $trait::$method(self, *other);
+            }
+        }
+
+        impl<N: Float> $trait<Complex<N>> for &Complex<N> {
+            type Output = Complex<N>;
+
+            fn $method(self, other: Complex<N>) -> Self::Output {
+                return $trait::$method(*self, other);
+            }
+        }
+
+        impl<N: Float> $trait<&Complex<N>> for &Complex<N> {
+            type Output = Complex<N>;
+
+            fn $method(self, other: &Complex<N>) -> Self::Output {
+                return $trait::$method(*self, *other);
+            }
+        }
+
+        impl<N: Float> $assign_trait<&Complex<N>> for Complex<N> {
+            fn $assign_method(&mut self, other: &Complex<N>) {
+                $assign_trait::$assign_method(self, *other);
+            }
+        }
+    };
+}
+
+// As above, plus real scalars on the right-hand side.
+macro_rules! impl_reference_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl_reference_complex_op!($trait, $method, $assign_trait, $assign_method);
+
+        impl<N: Float> $trait<&N> for Complex<N> {
+            type Output = Complex<N>;
+
+            fn $method(self, other: &N) -> Self::Output {
+                return $trait::$method(self, *other);
+            }
+        }
+
+        impl<N: Float> $trait<N> for &Complex<N> {
+            type Output = Complex<N>;
+
+            fn $method(self, other: N) -> Self::Output {
+                return $trait::$method(*self, other);
+            }
+        }
+
+        impl<N: Float> $trait<&N> for &Complex<N> {
+            type Output = Complex<N>;
+
+            fn $method(self, other: &N) -> Self::Output {
+                return $trait::$method(*self, *other);
+            }
+        }
+
+        impl<N: Float> $assign_trait<&N> for Complex<N> {
+            fn $assign_method(&mut self, other: &N) {
+                $assign_trait::$assign_method(self, *other);
+            }
+        }
+    };
+}
+
+impl_reference_op!(Add, add, AddAssign, add_assign);
+impl_reference_op!(Div, div, DivAssign, div_assign);
+impl_reference_op!(Mul, mul, MulAssign, mul_assign);
+impl_reference_op!(Rem, rem, RemAssign, rem_assign);
+impl_reference_op!(Sub, sub, SubAssign, sub_assign);
+impl_reference_complex_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_reference_complex_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_reference_complex_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl<N: Float> Neg for &Complex<N> {
+    type Output = Complex<N>;
+
+    fn neg(self) -> Self::Output {
+        return Complex::neg(self);
+    }
+}
+
+impl<N: Float> Not for &Complex<N> {
+    type Output = Complex<N>;
+
+    fn not(self) -> Self::Output {
+        return Complex::conj(self);
+    }
+}
+
+// Real scalars on the left-hand side of `Complex` and `Imaginary` operands. These can only be
+// implemented for concrete float types.
+macro_rules! impl_scalar_left_op {
+    ($scalar:ty, $other:ty => $output:ty, $trait:ident, $method:ident, |$lhs:ident, $rhs:ident| $body:expr) => {
+        impl $trait<$other> for $scalar {
+            type Output = $output;
+
+            fn $method(self, other: $other) -> Self::Output {
+                let ($lhs, $rhs) = (self, other);
+
+                return $body;
+            }
+        }
+
+        impl $trait<&$other> for $scalar {
+            type Output = $output;
+
+            fn $method(self, other: &$other) -> Self::Output {
+                return $trait::$method(self, *other);
+            }
+        }
+
+        impl $trait<$other> for &$scalar {
+            type Output = $output;
+
+            fn $method(self, other: $other) -> Self::Output {
+                return $trait::$method(*self, other);
+            }
+        }
+
+        impl $trait<&$other> for &$scalar {
+            type Output = $output;
+
+            fn $method(self, other: &$other) -> Self::Output {
+                return $trait::$method(*self, *other);
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_left_ops {
+    ($($scalar:ty),*) => {
+        $(
+            impl_scalar_left_op!($scalar, Complex<$scalar> => Complex<$scalar>, Add, add, |lhs, rhs| rhs.addf(lhs));
+            impl_scalar_left_op!($scalar, Complex<$scalar> => Complex<$scalar>, Div, div, |lhs, rhs| Complex::div(&Complex::from_real(lhs), &rhs));
+            impl_scalar_left_op!($scalar, Complex<$scalar> => Complex<$scalar>, Mul, mul, |lhs, rhs| rhs.mulf(lhs));
+            impl_scalar_left_op!($scalar, Complex<$scalar> => Complex<$scalar>, Rem, rem, |lhs, rhs| Complex::rem(&Complex::from_real(lhs), &rhs));
+            impl_scalar_left_op!($scalar, Complex<$scalar> => Complex<$scalar>, Sub, sub, |lhs, rhs| Complex::neg(&rhs).addf(lhs));
+
+            impl_scalar_left_op!($scalar, Imaginary<$scalar> => Complex<$scalar>, Add, add, |lhs, rhs| Complex::new(lhs, rhs.0));
+            // x / bi = -(x/b)i
+            impl_scalar_left_op!($scalar, Imaginary<$scalar> => Imaginary<$scalar>, Div, div, |lhs, rhs| Imaginary(-(lhs / rhs.0)));
+            impl_scalar_left_op!($scalar, Imaginary<$scalar> => Imaginary<$scalar>, Mul, mul, |lhs, rhs| Imaginary(lhs * rhs.0));
+            impl_scalar_left_op!($scalar, Imaginary<$scalar> => Complex<$scalar>, Sub, sub, |lhs, rhs| Complex::new(lhs, -rhs.0));
+        )*
+    };
+}
+
+impl_scalar_left_ops!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type C = Complex<f64>;
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference_operands() {
+        let (a, b, x) = (C::new(1.0, 2.0), C::new(3.0, -4.0), 2.0);
+
+        for (sum, difference, product, quotient, remainder) in [
+            (&a + &b, &a - &b, &a * &b, &a / &b, &a % &b),
+            (a + &b, a - &b, a * &b, a / &b, a % &b),
+            (&a + b, &a - b, &a * b, &a / b, &a % b)
+        ] {
+            assert_eq!(sum, C::new(4.0, -2.0));
+            assert_eq!(difference, C::new(-2.0, 6.0));
+            assert_eq!(product, C::new(11.0, 2.0));
+            assert_eq!(quotient, C::new(-0.2, 0.4));
+            assert_eq!(remainder, C::new(1.0, 2.0));
+        }
+
+        assert_eq!((&a + &x, &a - x, a * &x, &a / &x, &a % x), (C::new(3.0, 2.0), C::new(-1.0, 2.0), C::new(2.0, 4.0), C::new(0.5, 1.0), C::new(-1.0, 0.0)));
+        assert_eq!((-&a, !&a), (C::new(-1.0, -2.0), C::new(1.0, -2.0)));
+
+        let mut c = a;
+        c += &b;
+        c -= &x;
+        c *= &x;
+        c /= &b;
+        assert_eq!(c, C::new(1.12, 0.16));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference_bit_operands() {
+        // Bitwise results can be NaN, so compare bit patterns.
+        let bits = |z: C| z.to_array().map(f64::to_bits);
+        let (a, b) = (C::new(1.5, -3.0), C::new(-2.5, 0.75));
+        let apply = |f: fn(u64, u64) -> u64| [f(a.real.to_bits(), b.real.to_bits()), f(a.imaginary.to_bits(), b.imaginary.to_bits())];
+        let (and, or, xor) = (apply(|x, y| x & y), apply(|x, y| x | y), apply(|x, y| x ^ y));
+
+        for (result, expected) in [(a & b, and), (&a & b, and), (a & &b, and), (&a & &b, and), (a | b, or), (&a | b, or), (a | &b, or), (&a | &b, or), (a ^ b, xor), (&a ^ b, xor), (a ^ &b, xor), (&a ^ &b, xor)] {
+            assert_eq!(bits(result), expected);
+        }
+
+        let mut c = a;
+        c &= &b;
+        assert_eq!(bits(c), and);
+        let mut c = a;
+        c |= &b;
+        assert_eq!(bits(c), or);
+        let mut c = a;
+        c ^= &b;
+        assert_eq!(bits(c), xor);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn scalar_left_operands() {
+        let (z, x) = (C::new(3.0, -4.0), 2.0);
+
+        assert_eq!(x + z, C::new(5.0, -4.0));
+        assert_eq!(x - z, C::new(-1.0, 4.0));
+        assert_eq!(x * z, C::new(6.0, -8.0));
+        assert_eq!(x / z, C::new(0.24, 0.32));
+        assert_eq!(x % z, C::new(2.0, 0.0));
+        assert_eq!(10.0 % z, C::new(-1.0, -2.0));
+        assert_eq!((&x + &z, &x - z, x * &z), (C::new(5.0, -4.0), C::new(-1.0, 4.0), C::new(6.0, -8.0)));
+        assert_eq!(2.0f32 * Complex::new(1.5f32, -1.0), Complex::new(3.0f32, -2.0));
+    }
+
+    #[test]
+    fn mixed_precision() {
+        let (wide, narrow) = (C::new(1.0, 2.0), Complex::new(0.1f32, -0.5f32));
+        let widened = C::new(0.1f32 as f64, -0.5);
+
+        assert_eq!(wide + narrow, C::add(&wide, &widened));
+        assert_eq!(narrow + wide, C::add(&widened, &wide));
+        assert_eq!(wide - narrow, C::sub(&wide, &widened));
+        assert_eq!(narrow - wide, C::sub(&widened, &wide));
+        assert_eq!(wide * narrow, C::mul(&wide, &widened));
+        assert_eq!(narrow * wide, C::mul(&widened, &wide));
+        assert_eq!(wide / narrow, C::div(&wide, &widened));
+        assert_eq!(narrow / wide, C::div(&widened, &wide));
+        assert_eq!(wide * 0.1f32, wide.mulf(0.1f32 as f64));
+        assert_eq!(wide / 4f32, C::new(0.25, 0.5));
+
+        // Accumulating f32 samples in f64 keeps the bits an f32 sum would drop.
+        let mut sum = C::new(0.0, 0.0);
+        for _ in 0..10 {
+            sum += Complex::new(1e8f32, 0.0);
+            sum += Complex::new(1.0f32, 1.0);
+        }
+        sum -= 1.0f32;
+        sum *= 2.0f32;
+        sum /= Complex::new(2.0f32, 0.0);
+        assert_eq!(sum, C::new(1_000_000_009.0, 10.0));
+    }
+
+    #[test]
+    fn lattice_remainder() {
+        assert_eq!(C::new(7.0, 3.0) % C::new(2.0, 0.0), C::new(-1.0, -1.0));
+        assert_eq!(C::new(5.0, 5.0) % C::new(1.0, 1.0), C::new(0.0, 0.0));
+        assert_eq!(C::new(7.5, -2.25) % 2.0, C::new(-0.5, -0.25));
+
+        // Deterministic pseudo-random operands.
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            return (state >> 11) as f64 / (1u64 << 53) as f64 * 200.0 - 100.0;
+        };
+
+        for _ in 0..1000 {
+            let (a, d) = (C::new(next(), next()), C::new(next(), next()));
+            let r = a % d;
+
+            // The rounded quotient is within 1/2 of the exact one in each part.
+            assert!(r.amplitude() <= d.amplitude() / 2f64.sqrt() * (1.0 + 1e-12), "{a:?} % {d:?} = {r:?}");
+
+            let mut assigned = a;
+            assigned %= d;
+            assert_eq!(assigned, r);
+        }
+    }
+}
@@ -0,0 +1,360 @@
+// Minimal ZIP container support for .npz archives: only stored (uncompressed) entries,
+// with zip64 records when sizes, offsets or the entry count don't fit the classic fields.
+
+use super::io_util::{invalid_data, read_length};
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+
+const LOCAL_HEADER: u32 = 0x04034b50;
+const CENTRAL_HEADER: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x06054b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY: u32 = 0x06064b50;
+const ZIP64_LOCATOR: u32 = 0x07064b50;
+const ZIP64_EXTRA: u16 = 0x0001;
+
+const STORED: u16 = 0;
+// 1980-01-01 00:00, the earliest date MS-DOS timestamps can express.
+const DOS_DATE: u16 = (1 << 5) | 1;
+const DOS_TIME: u16 = 0;
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 != 0 { 0xEDB88320 ^ (crc >> 1) } else { crc >> 1 };
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    return table;
+}
+
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &byte in bytes {
+        crc = CRC_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+
+    return !crc;
+}
+
+// Little-endian field reader over a byte buffer.
+struct Fields<'a> {
+    bytes: &'a [u8],
+    position: usize
+}
+
+impl<'a> Fields<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        return Self {
+            bytes: bytes,
+            position: 0
+        };
+    }
+
+    fn take(&mut self, length: usize) -> io::Result<&'a [u8]> {
+        let end = self.position.checked_add(length).filter(|&end| end <= self.bytes.len()).ok_or_else(|| invalid_data("truncated zip record"))?;
+        let bytes = &self.bytes[self.position..end];
+
+        self.position = end;
+
+        return Ok(bytes);
+    }
+
+    fn u16(&mut self) -> io::Result<u16> {
+        return Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()));
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        return Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()));
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        return Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()));
+    }
+}
+
+fn read_at<R: Read+Seek>(reader: &mut R, offset: u64, length: u64) -> io::Result<Vec<u8>> {
+    reader.seek(SeekFrom::Start(offset))?;
+
+    return read_length(reader, length);
+}
+
+struct CentralEntry {
+    name: String,
+    crc: u32,
+    size: u64,
+    local_offset: u64
+}
+
+// Returns the entry count, size and offset of the central directory.
+fn find_central_directory<R: Read+Seek>(reader: &mut R) -> io::Result<(u64, u64, u64)> {
+    let length = reader.seek(SeekFrom::End(0))?;
+    // The end record is 22 bytes followed by a comment of at most 65535 bytes.
+    let tail_length = length.min(22 + 65535);
+    let tail_start = length - tail_length;
+    let tail = read_at(reader, tail_start, tail_length)?;
+
+    let position = (0..tail.len().saturating_sub(21)).rev()
+        .find(|&i| tail[i..i + 4] == END_OF_CENTRAL_DIRECTORY.to_le_bytes())
+        .ok_or_else(|| invalid_data("zip end of central directory not found"))?;
+
+    let mut fields = Fields::new(&tail[position + 4..]);
+    let _disk = fields.u16()?;
+    let _directory_disk = fields.u16()?;
+    let _disk_entries = fields.u16()?;
+    let entries = fields.u16()?;
+    let size = fields.u32()?;
+    let offset = fields.u32()?;
+
+    if entries != u16::MAX && size != u32::MAX && offset != u32::MAX {
+        return Ok((entries as u64, size as u64, offset as u64));
+    }
+
+    // Zip64: the locator sits immediately before the classic end record.
+    let locator_position = (tail_start + position as u64).checked_sub(20).ok_or_else(|| invalid_data("zip64 locator not found"))?;
+    let locator = read_at(reader, locator_position, 20)?;
+    let mut fields = Fields::new(&locator);
+
+    if fields.u32()? != ZIP64_LOCATOR {
+        return Err(invalid_data("zip64 locator not found"));
+    }
+
+    let _disk = fields.u32()?;
+    let record_offset = fields.u64()?;
+    let record = read_at(reader, record_offset, 56)?;
+    let mut fields = Fields::new(&record);
+
+    if fields.u32()? != ZIP64_END_OF_CENTRAL_DIRECTORY {
+        return Err(invalid_data("zip64 end of central directory not found"));
+    }
+
+    let _record_size = fields.u64()?;
+    let _version_made_by = fields.u16()?;
+    let _version_needed = fields.u16()?;
+    let _disk = fields.u32()?;
+    let _directory_disk = fields.u32()?;
+    let _disk_entries = fields.u64()?;
+    let entries = fields.u64()?;
+    let size = fields.u64()?;
+    let offset = fields.u64()?;
+
+    return Ok((entries, size, offset));
+}
+
+fn read_central_entry(fields: &mut Fields) -> io::Result<CentralEntry> {
+    if fields.u32()? != CENTRAL_HEADER {
+        return Err(invalid_data("invalid zip central directory header"));
+    }
+
+    let _version_made_by = fields.u16()?;
+    let _version_needed = fields.u16()?;
+    let flags = fields.u16()?;
+    let method = fields.u16()?;
+    let _time = fields.u16()?;
+    let _date = fields.u16()?;
+    let crc = fields.u32()?;
+    let mut compressed_size = fields.u32()? as u64;
+    let mut size = fields.u32()? as u64;
+    let name_length = fields.u16()? as usize;
+    let extra_length = fields.u16()? as usize;
+    let comment_length = fields.u16()? as usize;
+    let _disk = fields.u16()?;
+    let _internal_attributes = fields.u16()?;
+    let _external_attributes = fields.u32()?;
+    let mut local_offset = fields.u32()? as u64;
+    let name = String::from_utf8(fields.take(name_length)?.to_vec()).map_err(|_| invalid_data("zip entry name is not UTF-8"))?;
+    let mut extra = Fields::new(fields.take(extra_length)?);
+    let _comment = fields.take(comment_length)?;
+
+    if method != STORED {
+        return Err(invalid_data("compressed npz entries are not supported"));
+    }
+    if flags & 1 != 0 {
+        return Err(invalid_data("encrypted zip entries are not supported"));
+    }
+
+    // The zip64 extra field holds, in order, only those values whose classic field is saturated.
+    while extra.position < extra.bytes.len() {
+        let id = extra.u16()?;
+        let length = extra.u16()? as usize;
+        let mut data = Fields::new(extra.take(length)?);
+
+        if id == ZIP64_EXTRA {
+            if size == u32::MAX as u64 {
+                size = data.u64()?;
+            }
+            if compressed_size == u32::MAX as u64 {
+                compressed_size = data.u64()?;
+            }
+            if local_offset == u32::MAX as u64 {
+                local_offset = data.u64()?;
+            }
+        }
+    }
+
+    if compressed_size != size {
+        return Err(invalid_data("stored zip entry has mismatched sizes"));
+    }
+
+    return Ok(CentralEntry {
+        name: name,
+        crc: crc,
+        size: size,
+        local_offset: local_offset
+    });
+}
+
+/// Reads every entry of an archive containing only stored entries, checking their CRCs.
+pub(crate) fn read_stored_entries<R: Read+Seek>(mut reader: R) -> io::Result<Vec<(String, Vec<u8>)>> {
+    let (count, size, offset) = find_central_directory(&mut reader)?;
+    let directory = read_at(&mut reader, offset, size)?;
+    let mut fields = Fields::new(&directory);
+    let mut entries = Vec::new();
+
+    for _ in 0..count {
+        let entry = read_central_entry(&mut fields)?;
+        let header = read_at(&mut reader, entry.local_offset, 30)?;
+        let mut header_fields = Fields::new(&header);
+
+        if header_fields.u32()? != LOCAL_HEADER {
+            return Err(invalid_data("invalid zip local header"));
+        }
+
+        header_fields.take(22)?;
+        let name_length = header_fields.u16()? as u64;
+        let extra_length = header_fields.u16()? as u64;
+        let data = read_at(&mut reader, entry.local_offset + 30 + name_length + extra_length, entry.size)?;
+
+        if crc32(&data) != entry.crc {
+            return Err(invalid_data("zip entry CRC mismatch"));
+        }
+
+        entries.push((entry.name, data));
+    }
+
+    return Ok(entries);
+}
+
+/// Writes a complete archive with every entry stored uncompressed.
+pub(crate) fn write_stored_entries<W: Write>(mut writer: W, entries: &[(String, Vec<u8>)]) -> io::Result<()> {
+    let mut offset = 0u64;
+    let mut directory = Vec::new();
+
+    for (name, data) in entries {
+        let name = name.as_bytes();
+        let size = data.len() as u64;
+        let crc = crc32(data);
+        let zip64 = size >= u32::MAX as u64 || offset >= u32::MAX as u64;
+        let version = if zip64 { 45u16 } else { 20u16 };
+        let classic_size = if zip64 { u32::MAX } else { size as u32 };
+
+        let mut local = Vec::with_capacity(30 + name.len() + 20);
+        local.extend_from_slice(&LOCAL_HEADER.to_le_bytes());
+        local.extend_from_slice(&version.to_le_bytes());
+        local.extend_from_slice(&0u16.to_le_bytes());
+        local.extend_from_slice(&STORED.to_le_bytes());
+        local.extend_from_slice(&DOS_TIME.to_le_bytes());
+        local.extend_from_slice(&DOS_DATE.to_le_bytes());
+        local.extend_from_slice(&crc.to_le_bytes());
+        local.extend_from_slice(&classic_size.to_le_bytes());
+        local.extend_from_slice(&classic_size.to_le_bytes());
+        local.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        local.extend_from_slice(&(if zip64 { 20u16 } else { 0u16 }).to_le_bytes());
+        local.extend_from_slice(name);
+
+        if zip64 {
+            local.extend_from_slice(&ZIP64_EXTRA.to_le_bytes());
+            local.extend_from_slice(&16u16.to_le_bytes());
+            local.extend_from_slice(&size.to_le_bytes());
+            local.extend_from_slice(&size.to_le_bytes());
+        }
+
+        let mut extra = Vec::new();
+
+        if zip64 {
+            extra.extend_from_slice(&ZIP64_EXTRA.to_le_bytes());
+            extra.extend_from_slice(&24u16.to_le_bytes());
+            extra.extend_from_slice(&size.to_le_bytes());
+            extra.extend_from_slice(&size.to_le_bytes());
+            extra.extend_from_slice(&offset.to_le_bytes());
+        }
+
+        directory.extend_from_slice(&CENTRAL_HEADER.to_le_bytes());
+        directory.extend_from_slice(&version.to_le_bytes());
+        directory.extend_from_slice(&version.to_le_bytes());
+        directory.extend_from_slice(&0u16.to_le_bytes());
+        directory.extend_from_slice(&STORED.to_le_bytes());
+        directory.extend_from_slice(&DOS_TIME.to_le_bytes());
+        directory.extend_from_slice(&DOS_DATE.to_le_bytes());
+        directory.extend_from_slice(&crc.to_le_bytes());
+        directory.extend_from_slice(&classic_size.to_le_bytes());
+        directory.extend_from_slice(&classic_size.to_le_bytes());
+        directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        directory.extend_from_slice(&(extra.len() as u16).to_le_bytes());
+        directory.extend_from_slice(&0u16.to_le_bytes());
+        directory.extend_from_slice(&0u16.to_le_bytes());
+        directory.extend_from_slice(&0u16.to_le_bytes());
+        directory.extend_from_slice(&0u32.to_le_bytes());
+        directory.extend_from_slice(&(if zip64 { u32::MAX } else { offset as u32 }).to_le_bytes());
+        directory.extend_from_slice(name);
+        directory.extend_from_slice(&extra);
+
+        writer.write_all(&local)?;
+        writer.write_all(data)?;
+        offset += local.len() as u64 + size;
+    }
+
+    writer.write_all(&directory)?;
+
+    let count = entries.len() as u64;
+    let size = directory.len() as u64;
+    let zip64 = count >= u16::MAX as u64 || size >= u32::MAX as u64 || offset >= u32::MAX as u64;
+
+    if zip64 {
+        let record_offset = offset + size;
+        let mut record = Vec::with_capacity(56 + 20);
+
+        record.extend_from_slice(&ZIP64_END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+        record.extend_from_slice(&44u64.to_le_bytes());
+        record.extend_from_slice(&45u16.to_le_bytes());
+        record.extend_from_slice(&45u16.to_le_bytes());
+        record.extend_from_slice(&0u32.to_le_bytes());
+        record.extend_from_slice(&0u32.to_le_bytes());
+        record.extend_from_slice(&count.to_le_bytes());
+        record.extend_from_slice(&count.to_le_bytes());
+        record.extend_from_slice(&size.to_le_bytes());
+        record.extend_from_slice(&offset.to_le_bytes());
+
+        record.extend_from_slice(&ZIP64_LOCATOR.to_le_bytes());
+        record.extend_from_slice(&0u32.to_le_bytes());
+        record.extend_from_slice(&record_offset.to_le_bytes());
+        record.extend_from_slice(&1u32.to_le_bytes());
+
+        writer.write_all(&record)?;
+    }
+
+    let mut end = Vec::with_capacity(22);
+    end.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+    end.extend_from_slice(&0u16.to_le_bytes());
+    end.extend_from_slice(&0u16.to_le_bytes());
+    end.extend_from_slice(&(if zip64 { u16::MAX } else { count as u16 }).to_le_bytes());
+    end.extend_from_slice(&(if zip64 { u16::MAX } else { count as u16 }).to_le_bytes());
+    end.extend_from_slice(&(if zip64 { u32::MAX } else { size as u32 }).to_le_bytes());
+    end.extend_from_slice(&(if zip64 { u32::MAX } else { offset as u32 }).to_le_bytes());
+    end.extend_from_slice(&0u16.to_le_bytes());
+
+    return writer.write_all(&end);
+}